@@ -1,8 +1,40 @@
 //! Constants for the Deadman's Switch program.
 
+use anchor_lang::prelude::*;
+
 /// Seeds for Vault PDA
 pub const VAULT_SEED: &[u8] = b"vault";
 
+/// Seeds for Estate (shared heartbeat) PDA
+pub const ESTATE_SEED: &[u8] = b"estate";
+
+/// Seeds for administrative Proposal PDA
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+
+/// Seeds for social Recovery PDA
+pub const RECOVERY_SEED: &[u8] = b"recovery";
+
+/// Seeds for tiered EscalationPlan PDA
+pub const ESCALATION_SEED: &[u8] = b"escalation";
+
+/// Seeds for per-asset AllocationTable PDA
+pub const ALLOCATION_SEED: &[u8] = b"allocation";
+
+/// Seeds for per-NFT NftRecord PDA
+pub const NFT_SEED: &[u8] = b"nft";
+
+/// Seeds for per-stake-account StakeRecord PDA
+pub const STAKE_SEED: &[u8] = b"stake";
+
+/// Seeds for the AutoStake PDA (and the native stake account and SOL escrow derived with it)
+pub const AUTO_STAKE_SEED: &[u8] = b"auto_stake";
+
+/// Seeds for per-authority AuthorityRecord PDA
+pub const AUTHORITY_SEED: &[u8] = b"authority";
+
+/// Seeds for the LegacyPlan (stored instructions) PDA
+pub const LEGACY_SEED: &[u8] = b"legacy";
+
 /// Maximum length of IPFS CID (CIDv1 base32 = ~59 chars, add padding)
 pub const MAX_IPFS_CID_LEN: usize = 64;
 
@@ -11,3 +43,66 @@ pub const MAX_ENCRYPTED_KEY_LEN: usize = 128;
 
 /// Maximum length of vault name
 pub const MAX_VAULT_NAME_LEN: usize = 32;
+
+/// Maximum number of co-owners on a joint vault (in addition to the owner)
+pub const MAX_CO_OWNERS: usize = 4;
+
+/// Maximum number of additional owner authorities (admin-only keys)
+pub const MAX_OWNER_AUTHORITIES: usize = 5;
+
+/// Maximum number of approvals recorded on a proposal
+pub const MAX_PROPOSAL_APPROVALS: usize = 1 + MAX_CO_OWNERS + MAX_OWNER_AUTHORITIES;
+
+/// Maximum number of recovery guardians per vault
+pub const MAX_GUARDIANS: usize = 5;
+
+/// Protocol cap on how far ahead a snooze may push the deadline (365 days)
+pub const MAX_SNOOZE_DURATION: i64 = 365 * 24 * 60 * 60;
+
+/// Maximum number of fallback recipients per vault
+pub const MAX_FALLBACK_RECIPIENTS: usize = 3;
+
+/// Maximum number of escalation tiers per vault
+pub const MAX_ESCALATION_TIERS: usize = 4;
+
+/// Maximum number of assets (native SOL and token mints) in an allocation table
+pub const MAX_ALLOCATED_ASSETS: usize = 4;
+
+/// Maximum number of recipients sharing one allocated asset
+pub const MAX_ALLOCATION_SHARES: usize = 5;
+
+/// Basis points making up a whole asset (allocation shares must sum to this)
+pub const TOTAL_BASIS_POINTS: u16 = 10_000;
+
+/// Maximum number of vaults an inheritance may cascade through
+pub const MAX_CASCADE_DEPTH: usize = 8;
+
+/// Maximum number of liveness adapter programs per vault
+pub const MAX_LIVENESS_ADAPTERS: usize = 3;
+
+/// Maximum length of the memo sent to the recipient on release
+pub const MAX_NOTICE_MEMO_LEN: usize = 64;
+
+/// SPL stake pool program (auto-staking into a liquid staking token)
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey = pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// Maximum number of stored instructions in a legacy plan
+pub const MAX_LEGACY_STEPS: usize = 4;
+
+/// Maximum number of accounts per stored instruction
+pub const MAX_LEGACY_STEP_ACCOUNTS: usize = 8;
+
+/// Maximum data length of a stored instruction
+pub const MAX_LEGACY_STEP_DATA_LEN: usize = 128;
+
+/// Time after release before a failing legacy step may be skipped (30 days)
+pub const LEGACY_SKIP_DELAY: i64 = 30 * 24 * 60 * 60;
+
+/// Programs a stored legacy instruction may call with the vault's signature
+pub const LEGACY_PROGRAM_WHITELIST: [Pubkey; 5] = [
+    pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"), // SPL Token
+    pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"), // Token-2022
+    pubkey!("Stake11111111111111111111111111111111111111"), // Native stake
+    pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"), // Memo
+    pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf"), // Squads v4 multisig
+];
@@ -54,4 +54,271 @@ pub enum VaultError {
 
     #[msg("Tokens already claimed")]
     TokensAlreadyClaimed,
+
+    #[msg("Vault is already linked to an estate")]
+    AlreadyLinked,
+
+    #[msg("Vault is not linked to an estate")]
+    NotLinked,
+
+    #[msg("Estate account does not match the vault's linked estate")]
+    EstateMismatch,
+
+    #[msg("Too many co-owners")]
+    TooManyCoOwners,
+
+    #[msg("Key list contains a duplicate or an existing owner key")]
+    DuplicateCoOwner,
+
+    #[msg("Signature threshold must be between 1 and the number of owners")]
+    InvalidThreshold,
+
+    #[msg("Not enough owner signatures for this action")]
+    InsufficientSignatures,
+
+    #[msg("Too many owner authorities")]
+    TooManyAuthorities,
+
+    #[msg("Proposal does not match this vault or action")]
+    ProposalMismatch,
+
+    #[msg("Proposal has already been executed")]
+    ProposalExecuted,
+
+    #[msg("Signer has already approved this proposal")]
+    AlreadyApproved,
+
+    #[msg("Too many recovery guardians")]
+    TooManyGuardians,
+
+    #[msg("Only a registered recovery guardian can perform this action")]
+    NotGuardian,
+
+    #[msg("A recovery is already pending")]
+    RecoveryPending,
+
+    #[msg("No recovery is pending")]
+    NoPendingRecovery,
+
+    #[msg("Recovery timelock has not elapsed yet")]
+    RecoveryTimelocked,
+
+    #[msg("Recovery guardians were registered by a previous owner")]
+    StaleRecovery,
+
+    #[msg("Snooze must end in the future and within the maximum snooze duration")]
+    InvalidSnooze,
+
+    #[msg("This action is not available in the vault's release mode")]
+    ReleaseModeMismatch,
+
+    #[msg("Release date must be in the future and can only be moved later")]
+    InvalidReleaseDate,
+
+    #[msg("Recipient cannot claim before the claim-not-before date")]
+    ClaimTooEarly,
+
+    #[msg("Only the minor's guardian can perform this action")]
+    NotMinorGuardian,
+
+    #[msg("Destination does not match the guardian's restricted destination")]
+    InvalidDestination,
+
+    #[msg("Too many fallback recipients")]
+    TooManyFallbacks,
+
+    #[msg("Claim deadline has not passed yet")]
+    ClaimDeadlineNotReached,
+
+    #[msg("No fallback recipient left to pass claim rights to")]
+    NoFallbackRecipient,
+
+    #[msg("Nothing left to claim")]
+    NothingToClaim,
+
+    #[msg("Too many escalation tiers")]
+    TooManyTiers,
+
+    #[msg("Escalation tiers must have strictly increasing inactivity thresholds")]
+    InvalidTierOrder,
+
+    #[msg("Escalation has already started; tiers can no longer be changed")]
+    EscalationStarted,
+
+    #[msg("Vault has an escalation plan that must be provided")]
+    EscalationPlanRequired,
+
+    #[msg("Invalid escalation tier index")]
+    InvalidTierIndex,
+
+    #[msg("Escalation tier has not been released yet")]
+    TierNotReleased,
+
+    #[msg("Only the tier beneficiary can perform this action")]
+    NotTierBeneficiary,
+
+    #[msg("Vault has an allocation table that must be provided")]
+    AllocationTableRequired,
+
+    #[msg("Too many allocated assets or shares")]
+    TooManyAllocations,
+
+    #[msg("Allocation shares must be non-zero, unique per asset and sum to 10000 basis points")]
+    InvalidAllocation,
+
+    #[msg("Vault routes assets through an allocation table; claim each share instead")]
+    AllocationTableActive,
+
+    #[msg("Recipient vault chain leads back to the source vault")]
+    CascadeCycle,
+
+    #[msg("Recipient vault chain is missing or out of order in remaining accounts")]
+    CascadeChainIncomplete,
+
+    #[msg("Recipient vault chain is too deep")]
+    CascadeTooDeep,
+
+    #[msg("Vaults with an escalation plan or allocation table cannot pass in succession")]
+    SuccessionBlocked,
+
+    #[msg("Successor must name a different recipient")]
+    InvalidSuccessorRecipient,
+
+    #[msg("Vault is irrevocable; this change is no longer allowed")]
+    VaultIrrevocable,
+
+    #[msg("Vault contents were destroyed on expiry")]
+    VaultDestroyed,
+
+    #[msg("Destroy-on-expiry cannot be combined with escalation tiers")]
+    ReleaseActionConflict,
+
+    #[msg("Accounts needed to dispose of the locked assets were not provided")]
+    DestroyAccountsRequired,
+
+    #[msg("Missing or invalid ed25519 check-in signature")]
+    InvalidCheckInSignature,
+
+    #[msg("Signed check-in has expired")]
+    CheckInExpired,
+
+    #[msg("Signed check-in nonce does not match the vault")]
+    InvalidPingNonce,
+
+    #[msg("Vault uses a hash chain; check-ins must reveal the next preimage")]
+    PreimageRequired,
+
+    #[msg("Vault has no hash chain committed")]
+    NoHashChain,
+
+    #[msg("Hash chain is exhausted; the owner must commit a new one")]
+    HashChainExhausted,
+
+    #[msg("Preimage does not hash to the current chain head")]
+    InvalidPreimage,
+
+    #[msg("Hash chain must have at least one remaining ping")]
+    InvalidHashChain,
+
+    #[msg("Too many liveness adapters")]
+    TooManyAdapters,
+
+    #[msg("Liveness adapters must be unique programs other than this one")]
+    InvalidAdapter,
+
+    #[msg("Calling program is not a registered liveness adapter")]
+    NotLivenessAdapter,
+
+    #[msg("Liveness adapter pinged too recently")]
+    AdapterRateLimited,
+
+    #[msg("Vault is not within its expiry warning window")]
+    NotInWarningWindow,
+
+    #[msg("Expiry was already announced this heartbeat cycle")]
+    AlreadyAnnounced,
+
+    #[msg("Release notice memo too long")]
+    NoticeMemoTooLong,
+
+    #[msg("Recipient and memo program are required to send the release notice")]
+    NoticeAccountsRequired,
+
+    #[msg("Mint is not an NFT (supply 1, no decimals)")]
+    NotAnNft,
+
+    #[msg("Token Metadata accounts are required to move a programmable NFT")]
+    ProgrammableAccountsRequired,
+
+    #[msg("Vault still holds locked NFTs")]
+    NftsStillLocked,
+
+    #[msg("Stake account must be initialized with the owner as staker and withdrawer")]
+    InvalidStakeAccount,
+
+    #[msg("Stake lockup is in force; its custodian must co-sign")]
+    StakeLockupInForce,
+
+    #[msg("Vault still holds locked stake accounts")]
+    StakeAccountsStillLocked,
+
+    #[msg("Locked SOL is auto-staked; settle it with claim_sol or settle_auto_stake first")]
+    AutoStakeActive,
+
+    #[msg("AutoStake account is required for vaults with auto-staked SOL")]
+    AutoStakeRequired,
+
+    #[msg("Stake accounts are required to settle auto-staked SOL")]
+    AutoStakeAccountsRequired,
+
+    #[msg("Stake is still cooling down; claim again once it is inactive")]
+    StakeCoolingDown,
+
+    #[msg("Invalid stake pool account")]
+    InvalidStakePool,
+
+    #[msg("Signer does not hold this authority")]
+    AuthorityNotHeld,
+
+    #[msg("Only mint and freeze authorities are deposited from a mint")]
+    InvalidAuthorityKind,
+
+    #[msg("Mint or program data account is required to hand over this authority")]
+    CustodyAccountsRequired,
+
+    #[msg("Vault still holds custodied authorities")]
+    AuthoritiesStillCustodied,
+
+    #[msg("Too many legacy steps")]
+    TooManyLegacySteps,
+
+    #[msg("Legacy step exceeds size limits or requires a signer other than the vault")]
+    InvalidLegacyStep,
+
+    #[msg("Legacy steps may only call whitelisted programs")]
+    ProgramNotWhitelisted,
+
+    #[msg("Supplied accounts do not match the stored instruction")]
+    LegacyAccountMismatch,
+
+    #[msg("No legacy steps left to execute")]
+    NoPendingLegacySteps,
+
+    #[msg("Vault still has stored legacy steps")]
+    LegacyStepsPending,
+
+    #[msg("Escalation tiers still hold unclaimed assets or bounties")]
+    TiersUnclaimed,
+
+    #[msg("Vault has a legacy plan that must be provided")]
+    LegacyPlanRequired,
+
+    #[msg("Legacy steps cannot be skipped until the skip delay has passed")]
+    LegacySkipTooEarly,
+
+    #[msg("Vault is linked to an estate; check in through the estate instead")]
+    LinkedToEstate,
+
+    #[msg("Vault has recovery guardians whose account must be provided")]
+    RecoveryRequired,
 }
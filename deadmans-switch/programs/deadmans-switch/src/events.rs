@@ -0,0 +1,126 @@
+//! Events emitted by the Deadman's Switch program.
+
+use anchor_lang::prelude::*;
+
+/// Claim rights passed to the next fallback recipient after the claim deadline.
+#[event]
+pub struct ClaimRightsPassed {
+    pub vault: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    /// Position of this handoff in the vault's custody log
+    pub handoff_index: u8,
+    pub timestamp: i64,
+}
+
+/// An escalation tier was released to its beneficiary.
+#[event]
+pub struct TierReleased {
+    pub vault: Pubkey,
+    pub tier_index: u8,
+    pub beneficiary: Pubkey,
+    pub bounty_lamports: u64,
+    pub timestamp: i64,
+}
+
+/// Assets cascaded from a released vault into its recipient vault's locked balances.
+#[event]
+pub struct CascadeClaimed {
+    pub source_vault: Pubkey,
+    pub target_vault: Pubkey,
+    /// Token mint of the cascaded asset (None = native SOL)
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// The recipient took over a released vault as its new owner.
+#[event]
+pub struct VaultSucceeded {
+    pub vault: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub new_recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+/// A vault was released to its recipient, by a hunter after expiry or by its owner.
+#[event]
+pub struct VaultReleased {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    /// Hunter who triggered the release, or the owner for an immediate release
+    pub released_by: Pubkey,
+    /// Bounty paid to the hunter (zero for an immediate release)
+    pub bounty_lamports: u64,
+    /// Bounty credited to the recipient's locked SOL by an immediate release
+    pub bounty_to_recipient: u64,
+    pub timestamp: i64,
+}
+
+/// An expired vault's payload was wiped instead of released; pinning services should unpin the CID.
+#[event]
+pub struct VaultDestroyed {
+    pub vault: Pubkey,
+    /// CID of the encrypted file that was wiped from account data
+    pub ipfs_cid: String,
+    pub hunter: Pubkey,
+    pub bounty_lamports: u64,
+    pub lamports_returned: u64,
+    pub tokens_burned: u64,
+    pub tokens_returned: u64,
+    pub timestamp: i64,
+}
+
+/// A keeper announced that a vault is about to expire.
+#[event]
+pub struct ExpiryAnnounced {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub announcer: Pubkey,
+    pub expires_at: i64,
+    pub bounty_lamports: u64,
+    pub timestamp: i64,
+}
+
+/// Locked SOL was delegated on the estate's behalf.
+#[event]
+pub struct SolAutoStaked {
+    pub vault: Pubkey,
+    /// Native stake account or stake pool
+    pub target: Pubkey,
+    pub principal: u64,
+    pub timestamp: i64,
+}
+
+/// Auto-staked SOL was settled or unstaked, separating principal from rewards.
+#[event]
+pub struct AutoStakeSettled {
+    pub vault: Pubkey,
+    pub target: Pubkey,
+    pub principal: u64,
+    /// Lamports withdrawn (native stake) or the LST's lamport value (stake pool)
+    pub value: u64,
+    pub rewards: u64,
+    pub timestamp: i64,
+}
+
+/// A stored legacy instruction was executed with the vault's signature.
+#[event]
+pub struct LegacyStepExecuted {
+    pub vault: Pubkey,
+    pub step_index: u8,
+    pub program_id: Pubkey,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// A stored legacy instruction that could not run was skipped.
+#[event]
+pub struct LegacyStepSkipped {
+    pub vault: Pubkey,
+    pub step_index: u8,
+    pub program_id: Pubkey,
+    pub skipped_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,68 @@
+//! Announce that a vault is close to its deadline (permissionless).
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct AnnounceExpiring<'info> {
+    #[account(
+        mut,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+        constraint = vault.warning_window > 0 @ VaultError::NotInWarningWindow,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The keeper announcing the expiry and receiving the notification bounty
+    #[account(mut)]
+    pub announcer: Signer<'info>,
+
+    /// Estate providing the shared heartbeat (required only for linked vaults)
+    pub estate: Option<Account<'info, Estate>>,
+}
+
+impl<'info> AnnounceExpiring<'info> {
+    /// Handler for announce_expiring instruction.
+    /// Succeeds once per heartbeat cycle while the deadline is within the warning
+    /// window, paying the notification bounty from the owner-funded pool.
+    pub fn handler(&mut self) -> Result<()> {
+        let vault = &mut self.vault;
+        let clock = Clock::get()?;
+        let estate = vault.linked_estate(self.estate.as_ref())?;
+
+        let expires_at = vault.release_time(estate)?;
+        let window_start = expires_at
+            .checked_sub(vault.warning_window)
+            .ok_or(VaultError::Overflow)?;
+        require!(
+            (window_start..=expires_at).contains(&clock.unix_timestamp),
+            VaultError::NotInWarningWindow
+        );
+
+        // A new cycle begins with each check-in
+        require!(
+            vault.warned_at < vault.last_activity(estate),
+            VaultError::AlreadyAnnounced
+        );
+
+        let bounty = vault.notification_bounty.min(vault.notification_pool);
+        if bounty > 0 {
+            **vault.to_account_info().try_borrow_mut_lamports()? -= bounty;
+            **self.announcer.to_account_info().try_borrow_mut_lamports()? += bounty;
+            vault.notification_pool -= bounty;
+        }
+        vault.warned_at = clock.unix_timestamp;
+
+        emit!(ExpiryAnnounced {
+            vault: vault.key(),
+            owner: vault.owner,
+            announcer: self.announcer.key(),
+            expires_at,
+            bounty_lamports: bounty,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Expiry at {} announced by {}", expires_at, self.announcer.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//! Approve a pending administrative proposal.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        constraint = vault.is_admin_key(&approver.key()) @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, vault.key().as_ref(), proposal.nonce.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = vault @ VaultError::ProposalMismatch,
+        constraint = !proposal.executed @ VaultError::ProposalExecuted,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub approver: Signer<'info>,
+}
+
+impl<'info> ApproveProposal<'info> {
+    /// Handler for approve_proposal instruction.
+    pub fn handler(&mut self) -> Result<()> {
+        let proposal = &mut self.proposal;
+        let approver = self.approver.key();
+
+        require!(!proposal.approvals.contains(&approver), VaultError::AlreadyApproved);
+        require!(
+            proposal.approvals.len() < MAX_PROPOSAL_APPROVALS,
+            VaultError::TooManyAuthorities
+        );
+
+        proposal.approvals.push(approver);
+
+        msg!(
+            "Proposal {} approved by {} ({} approval(s))",
+            proposal.nonce,
+            approver,
+            proposal.approvals.len()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+//! Approve the recovery currently in progress.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        constraint = recovery.owner == vault.owner @ VaultError::StaleRecovery,
+        constraint = recovery.guardians.contains(&guardian.key()) @ VaultError::NotGuardian,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    pub guardian: Signer<'info>,
+}
+
+impl<'info> ApproveRecovery<'info> {
+    /// Handler for approve_recovery instruction.
+    pub fn handler(&mut self) -> Result<()> {
+        let guardian = self.guardian.key();
+        let pending = self
+            .recovery
+            .pending
+            .as_mut()
+            .ok_or(VaultError::NoPendingRecovery)?;
+
+        require!(!pending.approvals.contains(&guardian), VaultError::AlreadyApproved);
+        pending.approvals.push(guardian);
+
+        msg!(
+            "Recovery approved by guardian {} ({} approval(s))",
+            guardian,
+            pending.approvals.len()
+        );
+
+        Ok(())
+    }
+}
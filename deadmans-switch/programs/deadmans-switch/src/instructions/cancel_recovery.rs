@@ -0,0 +1,33 @@
+//! Cancel a recovery in progress. Only the current owner can do this.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        constraint = recovery.pending.is_some() @ VaultError::NoPendingRecovery,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> CancelRecovery<'info> {
+    /// Handler for cancel_recovery instruction.
+    pub fn handler(&mut self) -> Result<()> {
+        self.recovery.pending = None;
+
+        msg!("Recovery cancelled by owner {}", self.owner.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+//! Cascade a custodied authority from a released vault into its recipient vault.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use solana_sdk_ids::bpf_loader_upgradeable;
+use crate::{constants::*, errors::*, state::*};
+use super::claim_authority::hand_over_authority;
+
+#[derive(Accounts)]
+pub struct CascadeClaimAuthority<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::VaultDestroyed,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Vault named as recipient of the source vault
+    #[account(
+        mut,
+        constraint = target_vault.key() != vault.key() @ VaultError::CascadeCycle,
+        constraint = target_vault.key() == vault.recipient @ VaultError::NotRecipient,
+    )]
+    pub target_vault: Account<'info, Vault>,
+
+    /// Anyone may run the cascade; pays for the target's record
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Record of the authority in the source vault; closed to the caller
+    #[account(
+        mut,
+        close = caller,
+        seeds = [
+            AUTHORITY_SEED,
+            vault.key().as_ref(),
+            authority_record.target.as_ref(),
+            &[authority_record.kind as u8],
+        ],
+        bump = authority_record.bump,
+    )]
+    pub authority_record: Account<'info, AuthorityRecord>,
+
+    /// Record of the authority in the target vault
+    #[account(
+        init,
+        payer = caller,
+        space = AuthorityRecord::SPACE,
+        seeds = [
+            AUTHORITY_SEED,
+            target_vault.key().as_ref(),
+            authority_record.target.as_ref(),
+            &[authority_record.kind as u8],
+        ],
+        bump,
+    )]
+    pub target_authority_record: Account<'info, AuthorityRecord>,
+
+    /// The mint (mint and freeze authorities only)
+    #[account(mut)]
+    pub mint: Option<Account<'info, Mint>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// The program's data account (upgrade authorities only)
+    #[account(mut)]
+    pub program_data: Option<Account<'info, ProgramData>>,
+
+    /// CHECK: BPF upgradeable loader (upgrade authorities only); the set-authority instruction targets it by ID
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CascadeClaimAuthority<'info> {
+    /// Handler for cascade_claim_authority instruction.
+    /// Reassigns the authority to the target vault PDA and records it there.
+    pub fn handler(&mut self, chain: &[AccountInfo], record_bump: u8) -> Result<()> {
+        let clock = Clock::get()?;
+        self.vault.require_claim_open(clock.unix_timestamp)?;
+        self.target_vault
+            .require_acyclic_cascade(self.vault.key(), self.target_vault.key(), chain)?;
+
+        hand_over_authority(
+            &self.vault,
+            &self.authority_record,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            self.program_data.as_ref(),
+            self.bpf_loader.as_ref(),
+            &self.target_vault.to_account_info(),
+        )?;
+
+        let record = &mut self.target_authority_record;
+        record.vault = self.target_vault.key();
+        record.target = self.authority_record.target;
+        record.kind = self.authority_record.kind;
+        record.bump = record_bump;
+
+        self.vault.custodied_authorities -= 1;
+        let target_vault = &mut self.target_vault;
+        target_vault.custodied_authorities = target_vault
+            .custodied_authorities
+            .checked_add(1)
+            .ok_or(VaultError::Overflow)?;
+
+        msg!(
+            "Cascaded {:?} authority over {} into vault {}",
+            self.authority_record.kind,
+            self.authority_record.target,
+            target_vault.key()
+        );
+
+        Ok(())
+    }
+}
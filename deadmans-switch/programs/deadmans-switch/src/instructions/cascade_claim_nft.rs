@@ -0,0 +1,165 @@
+//! Cascade one locked NFT from a released vault into its recipient vault.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::Metadata,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{constants::*, errors::*, events::*, state::*};
+use super::claim_nft::{transfer_nft_out, NftTransferOut};
+
+#[derive(Accounts)]
+pub struct CascadeClaimNft<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::VaultDestroyed,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Vault named as recipient of the source vault
+    #[account(
+        mut,
+        constraint = target_vault.key() != vault.key() @ VaultError::CascadeCycle,
+        constraint = target_vault.key() == vault.recipient @ VaultError::NotRecipient,
+    )]
+    pub target_vault: Account<'info, Vault>,
+
+    /// Anyone may run the cascade; pays for the target's record and token account
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(address = nft_record.mint @ VaultError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Record of the NFT in the source vault; closed to the caller
+    #[account(
+        mut,
+        close = caller,
+        seeds = [NFT_SEED, vault.key().as_ref(), mint.key().as_ref()],
+        bump = nft_record.bump,
+    )]
+    pub nft_record: Account<'info, NftRecord>,
+
+    /// Record of the NFT in the target vault
+    #[account(
+        init,
+        payer = caller,
+        space = NftRecord::SPACE,
+        seeds = [NFT_SEED, target_vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub target_nft_record: Account<'info, NftRecord>,
+
+    /// Source vault's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Target vault's token account
+    /// Will be created if doesn't exist
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = target_vault,
+    )]
+    pub target_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Token Metadata account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Master edition (programmable NFTs only), verified by Token Metadata
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token record of the source vault's token account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub vault_token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token record of the target vault's token account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub target_token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rule set named by the NFT's programmable config, verified by Token Metadata
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Auth Rules program (only with a rule set), verified by Token Metadata
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+
+    /// CHECK: Instructions sysvar, required by Token Metadata for programmable transfers
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CascadeClaimNft<'info> {
+    /// Handler for cascade_claim_nft instruction.
+    /// Moves the NFT into the target vault and records it there, so the target's
+    /// own recipient claims it once that vault releases.
+    pub fn handler(&mut self, chain: &[AccountInfo], record_bump: u8) -> Result<()> {
+        let clock = Clock::get()?;
+        self.vault.require_claim_open(clock.unix_timestamp)?;
+        self.target_vault
+            .require_acyclic_cascade(self.vault.key(), self.target_vault.key(), chain)?;
+
+        transfer_nft_out(NftTransferOut {
+            vault: &self.vault,
+            record: &self.nft_record,
+            mint: &self.mint,
+            vault_token_account: &mut self.vault_token_account,
+            destination_token_account: self.target_token_account.to_account_info(),
+            destination_owner: self.target_vault.to_account_info(),
+            payer: self.caller.to_account_info(),
+            metadata: self.metadata.as_ref(),
+            edition: self.edition.as_ref(),
+            vault_token_record: self.vault_token_record.as_ref(),
+            destination_token_record: self.target_token_record.as_ref(),
+            authorization_rules: self.authorization_rules.as_ref(),
+            authorization_rules_program: self.authorization_rules_program.as_ref(),
+            token_metadata_program: self.token_metadata_program.as_ref(),
+            sysvar_instructions: self.sysvar_instructions.as_ref(),
+            token_program: &self.token_program,
+            associated_token_program: &self.associated_token_program,
+            system_program: &self.system_program,
+        })?;
+
+        let record = &mut self.target_nft_record;
+        record.vault = self.target_vault.key();
+        record.mint = self.mint.key();
+        record.is_programmable = self.nft_record.is_programmable;
+        record.bump = record_bump;
+
+        self.vault.locked_nfts -= 1;
+        let target_vault = &mut self.target_vault;
+        target_vault.locked_nfts = target_vault
+            .locked_nfts
+            .checked_add(1)
+            .ok_or(VaultError::Overflow)?;
+
+        emit!(CascadeClaimed {
+            source_vault: self.vault.key(),
+            target_vault: target_vault.key(),
+            mint: Some(self.mint.key()),
+            amount: 1,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Cascaded NFT {} into vault {}",
+            self.mint.key(),
+            target_vault.key()
+        );
+
+        Ok(())
+    }
+}
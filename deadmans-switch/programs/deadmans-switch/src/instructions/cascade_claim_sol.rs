@@ -0,0 +1,73 @@
+//! Cascade locked SOL from a released vault into its recipient vault.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct CascadeClaimSol<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = !vault.has_auto_stake @ VaultError::AutoStakeActive,
+        constraint = vault.locked_lamports > 0 @ VaultError::NoLockedSol,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Vault named as recipient (or allocation share recipient) of the source vault
+    #[account(
+        mut,
+        constraint = target_vault.key() != vault.key() @ VaultError::CascadeCycle,
+    )]
+    pub target_vault: Account<'info, Vault>,
+
+    /// Per-asset routing; required when the source vault has an allocation table
+    #[account(
+        mut,
+        seeds = [ALLOCATION_SEED, vault.key().as_ref()],
+        bump = allocation_table.bump,
+    )]
+    pub allocation_table: Option<Account<'info, AllocationTable>>,
+
+    /// Anyone may run the cascade; the recipient vault cannot sign
+    pub caller: Signer<'info>,
+}
+
+impl<'info> CascadeClaimSol<'info> {
+    /// Handler for cascade_claim_sol instruction.
+    /// Moves the target vault's share of locked SOL into its `locked_lamports`.
+    pub fn handler(&mut self, chain: &[AccountInfo]) -> Result<()> {
+        let vault = &mut self.vault;
+        let target_vault = &mut self.target_vault;
+        let clock = Clock::get()?;
+        vault.require_claim_open(clock.unix_timestamp)?;
+        target_vault.require_acyclic_cascade(vault.key(), target_vault.key(), chain)?;
+
+        let amount = vault.claimable_amount(
+            self.allocation_table.as_mut(),
+            None,
+            &target_vault.key(),
+            vault.locked_lamports,
+        )?;
+
+        **vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **target_vault.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        vault.locked_lamports -= amount;
+        target_vault.locked_lamports = target_vault
+            .locked_lamports
+            .checked_add(amount)
+            .ok_or(VaultError::Overflow)?;
+
+        emit!(CascadeClaimed {
+            source_vault: vault.key(),
+            target_vault: target_vault.key(),
+            mint: None,
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Cascaded {} lamports into vault {}", amount, target_vault.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! Cascade a locked stake account from a released vault into its recipient vault.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+use super::lock_stake_account::authorize_stake_account;
+
+#[derive(Accounts)]
+pub struct CascadeClaimStakeAccount<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::VaultDestroyed,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Vault named as recipient of the source vault
+    #[account(
+        mut,
+        constraint = target_vault.key() != vault.key() @ VaultError::CascadeCycle,
+        constraint = target_vault.key() == vault.recipient @ VaultError::NotRecipient,
+    )]
+    pub target_vault: Account<'info, Vault>,
+
+    /// Anyone may run the cascade; pays for the target's record
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// CHECK: Native stake account named by the stake record
+    #[account(mut, address = stake_record.stake_account @ VaultError::InvalidStakeAccount)]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// Record of the stake account in the source vault; closed to the caller
+    #[account(
+        mut,
+        close = caller,
+        seeds = [STAKE_SEED, vault.key().as_ref(), stake_account.key().as_ref()],
+        bump = stake_record.bump,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    /// Record of the stake account in the target vault
+    #[account(
+        init,
+        payer = caller,
+        space = StakeRecord::SPACE,
+        seeds = [STAKE_SEED, target_vault.key().as_ref(), stake_account.key().as_ref()],
+        bump,
+    )]
+    pub target_stake_record: Account<'info, StakeRecord>,
+
+    /// Lockup custodian; must co-sign while the stake account's lockup is in force
+    pub custodian: Option<Signer<'info>>,
+
+    /// CHECK: Native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CascadeClaimStakeAccount<'info> {
+    /// Handler for cascade_claim_stake_account instruction.
+    /// Hands both authorities to the target vault and records the stake account there.
+    pub fn handler(&mut self, chain: &[AccountInfo], record_bump: u8) -> Result<()> {
+        self.vault.require_claim_open(self.clock.unix_timestamp)?;
+        self.target_vault
+            .require_acyclic_cascade(self.vault.key(), self.target_vault.key(), chain)?;
+
+        let meta = StakeRecord::stake_meta(&self.stake_account)?;
+        let custodian = self.custodian.as_ref().map(|custodian| custodian.to_account_info());
+        let custodian = StakeRecord::lockup_custodian(&meta, custodian.as_ref(), &self.clock)?;
+
+        // PDA seeds for signing
+        let creator_key = self.vault.creator;
+        let vault_seed = self.vault.vault_seed;
+        let bump = self.vault.bump;
+
+        let seeds = &[
+            VAULT_SEED,
+            creator_key.as_ref(),
+            &vault_seed.to_le_bytes(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        authorize_stake_account(
+            &self.stake_account,
+            &self.vault.to_account_info(),
+            &self.clock.to_account_info(),
+            &self.target_vault.key(),
+            custodian,
+            signer_seeds,
+        )?;
+
+        let record = &mut self.target_stake_record;
+        record.vault = self.target_vault.key();
+        record.stake_account = self.stake_account.key();
+        record.bump = record_bump;
+
+        self.vault.locked_stake_accounts -= 1;
+        let target_vault = &mut self.target_vault;
+        target_vault.locked_stake_accounts = target_vault
+            .locked_stake_accounts
+            .checked_add(1)
+            .ok_or(VaultError::Overflow)?;
+
+        msg!(
+            "Cascaded stake account {} into vault {}",
+            self.stake_account.key(),
+            target_vault.key()
+        );
+
+        Ok(())
+    }
+}
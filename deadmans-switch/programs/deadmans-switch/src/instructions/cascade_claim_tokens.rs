@@ -0,0 +1,153 @@
+//! Cascade locked SPL tokens from a released vault into its recipient vault.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{constants::*, errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct CascadeClaimTokens<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = !vault.has_auto_stake @ VaultError::AutoStakeActive,
+        constraint = vault.token_mint.is_some() @ VaultError::NoTokensLocked,
+        constraint = vault.locked_tokens > 0 @ VaultError::TokensAlreadyClaimed,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Vault named as recipient (or allocation share recipient) of the source vault.
+    /// It can hold a single mint, so it must be empty or already hold this one.
+    #[account(
+        mut,
+        constraint = target_vault.key() != vault.key() @ VaultError::CascadeCycle,
+        constraint = target_vault.token_mint.unwrap_or(token_mint.key()) == token_mint.key() @ VaultError::InvalidMint,
+    )]
+    pub target_vault: Account<'info, Vault>,
+
+    /// Per-asset routing; required when the source vault has an allocation table
+    #[account(
+        mut,
+        seeds = [ALLOCATION_SEED, vault.key().as_ref()],
+        bump = allocation_table.bump,
+    )]
+    pub allocation_table: Option<Account<'info, AllocationTable>>,
+
+    /// Anyone may run the cascade; pays for the target token account if needed
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        constraint = token_mint.key() == vault.token_mint.unwrap() @ VaultError::InvalidMint,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Source vault's token account
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Target vault's token account
+    /// Will be created if doesn't exist
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = token_mint,
+        associated_token::authority = target_vault,
+    )]
+    pub target_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CascadeClaimTokens<'info> {
+    /// Handler for cascade_claim_tokens instruction.
+    /// Moves the target vault's share of locked tokens into its `locked_tokens`.
+    /// Rent from the emptied source token account goes to the caller.
+    pub fn handler(&mut self, chain: &[AccountInfo]) -> Result<()> {
+        let vault = &mut self.vault;
+        let target_vault = &mut self.target_vault;
+        let clock = Clock::get()?;
+        vault.require_claim_open(clock.unix_timestamp)?;
+        target_vault.require_acyclic_cascade(vault.key(), target_vault.key(), chain)?;
+
+        let amount = vault.claimable_amount(
+            self.allocation_table.as_mut(),
+            vault.token_mint,
+            &target_vault.key(),
+            vault.locked_tokens,
+        )?;
+
+        // PDA seeds for signing
+        let creator_key = vault.creator;
+        let vault_seed = vault.vault_seed;
+        let bump = vault.bump;
+
+        let seeds = &[
+            VAULT_SEED,
+            creator_key.as_ref(),
+            &vault_seed.to_le_bytes(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.vault_token_account.to_account_info(),
+                    to: self.target_token_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        self.vault_token_account.reload()?;
+        if self.vault_token_account.amount == 0 {
+            token::close_account(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    CloseAccount {
+                        account: self.vault_token_account.to_account_info(),
+                        destination: self.caller.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+            )?;
+        }
+
+        vault.locked_tokens -= amount;
+        target_vault.token_mint = Some(self.token_mint.key());
+        target_vault.locked_tokens = target_vault
+            .locked_tokens
+            .checked_add(amount)
+            .ok_or(VaultError::Overflow)?;
+
+        emit!(CascadeClaimed {
+            source_vault: vault.key(),
+            target_vault: target_vault.key(),
+            mint: Some(self.token_mint.key()),
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Cascaded {} tokens of mint {} into vault {}",
+            amount,
+            self.token_mint.key(),
+            target_vault.key()
+        );
+
+        Ok(())
+    }
+}
@@ -1,39 +1,77 @@
 //! Claim the vault contents and close it.
 
 use anchor_lang::prelude::*;
-use crate::{errors::*, state::*};
+use crate::{constants::*, errors::*, state::*};
 
 #[derive(Accounts)]
 pub struct ClaimAndClose<'info> {
     #[account(
         mut,
         close = recipient,
-        has_one = recipient @ VaultError::Unauthorized
+        has_one = recipient @ VaultError::Unauthorized,
+        constraint = !vault.has_allocation_table @ VaultError::AllocationTableActive,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::VaultDestroyed,
+        constraint = vault.locked_nfts == 0 @ VaultError::NftsStillLocked,
+        constraint = vault.locked_stake_accounts == 0 @ VaultError::StakeAccountsStillLocked,
+        constraint = !vault.has_auto_stake @ VaultError::AutoStakeActive,
+        constraint = vault.custodied_authorities == 0 @ VaultError::AuthoritiesStillCustodied,
+        constraint = !vault.has_legacy_plan @ VaultError::LegacyStepsPending,
     )]
     pub vault: Account<'info, Vault>,
 
     #[account(mut)]
     pub recipient: Signer<'info>,
+
+    /// Estate the vault is linked to (required only for linked vaults)
+    #[account(mut, constraint = vault.estate == Some(estate.key()) @ VaultError::EstateMismatch)]
+    pub estate: Option<Account<'info, Estate>>,
+
+    /// Escalation plan (required only for vaults with staged tiers); closed with the vault
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [ESCALATION_SEED, vault.key().as_ref()],
+        bump = escalation_plan.bump,
+    )]
+    pub escalation_plan: Option<Account<'info, EscalationPlan>>,
 }
 
 impl<'info> ClaimAndClose<'info> {
     /// Handler for claim_and_close instruction.
-    pub fn handler(&self) -> Result<()> {
+    pub fn handler(&mut self) -> Result<()> {
         let vault = &self.vault;
         let clock = Clock::get()?;
 
+        vault.require_claim_open(clock.unix_timestamp)?;
+
+        // Tier slices and bounties stay in the vault until their beneficiaries and hunters take them
+        if vault.has_escalation_plan {
+            let plan = self
+                .escalation_plan
+                .as_ref()
+                .ok_or(VaultError::EscalationPlanRequired)?;
+            require!(plan.reserved()? == (0, 0, 0), VaultError::TiersUnclaimed);
+        }
+
+        // Linked vaults follow their estate's timer, which is not available here
+        if vault.estate.is_some() {
+            require!(vault.is_released, VaultError::NotReleased);
+        }
+
         // Check if vault is expired (allow claim even if not formally released)
-        let expiry_time = vault
-            .last_check_in
-            .checked_add(vault.time_interval)
-            .ok_or(VaultError::Overflow)?;
+        let release_time = vault.release_time(None)?;
 
         require!(
-            clock.unix_timestamp > expiry_time || vault.is_released,
+            clock.unix_timestamp > release_time || vault.is_released,
             VaultError::NotExpired
         );
 
-        msg!("Vault claimed and closed by recipient: {}", vault.recipient);
+        if vault.estate.is_some() {
+            let estate = self.estate.as_mut().ok_or(VaultError::EstateMismatch)?;
+            estate.linked_vaults = estate.linked_vaults.saturating_sub(1);
+        }
+
+        msg!("Vault claimed and closed by recipient: {}", self.vault.recipient);
         msg!("Rent transferred to recipient.");
 
         Ok(())
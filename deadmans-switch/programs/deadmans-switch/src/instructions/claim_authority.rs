@@ -0,0 +1,159 @@
+//! Claim a custodied mint, freeze or upgrade authority from a released vault.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, SetAuthority, Token};
+use solana_loader_v3_interface::{get_program_data_address, instruction::set_upgrade_authority};
+use solana_sdk_ids::bpf_loader_upgradeable;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct ClaimAuthority<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The recipient, or the owner if the vault was destroyed on expiry
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// Record of the authority being claimed; closed to the claimant
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [
+            AUTHORITY_SEED,
+            vault.key().as_ref(),
+            authority_record.target.as_ref(),
+            &[authority_record.kind as u8],
+        ],
+        bump = authority_record.bump,
+    )]
+    pub authority_record: Account<'info, AuthorityRecord>,
+
+    /// The mint (mint and freeze authorities only)
+    #[account(mut)]
+    pub mint: Option<Account<'info, Mint>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// The program's data account (upgrade authorities only)
+    #[account(mut)]
+    pub program_data: Option<Account<'info, ProgramData>>,
+
+    /// CHECK: BPF upgradeable loader (upgrade authorities only); the set-authority instruction targets it by ID
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> ClaimAuthority<'info> {
+    /// Handler for claim_authority instruction.
+    /// Reassigns the authority from the vault PDA to the claimant. Vaults destroyed
+    /// on expiry hand their authorities back to the owner instead of the recipient.
+    pub fn handler(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        self.vault.require_claim_open(clock.unix_timestamp)?;
+
+        let heir = if self.vault.release_action == ReleaseAction::Release {
+            self.vault.recipient
+        } else {
+            self.vault.owner
+        };
+        require_keys_eq!(self.claimant.key(), heir, VaultError::NotRecipient);
+
+        hand_over_authority(
+            &self.vault,
+            &self.authority_record,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            self.program_data.as_ref(),
+            self.bpf_loader.as_ref(),
+            &self.claimant.to_account_info(),
+        )?;
+
+        let vault = &mut self.vault;
+        vault.custodied_authorities -= 1;
+
+        msg!(
+            "{:?} authority over {} claimed by {}",
+            self.authority_record.kind,
+            self.authority_record.target,
+            self.claimant.key()
+        );
+
+        Ok(())
+    }
+}
+
+/// Reassign a custodied authority from the vault PDA to `new_authority`.
+pub(crate) fn hand_over_authority<'info>(
+    vault: &Account<'info, Vault>,
+    record: &AuthorityRecord,
+    mint: Option<&Account<'info, Mint>>,
+    token_program: Option<&Program<'info, Token>>,
+    program_data: Option<&Account<'info, ProgramData>>,
+    bpf_loader: Option<&UncheckedAccount<'info>>,
+    new_authority: &AccountInfo<'info>,
+) -> Result<()> {
+    // PDA seeds for signing
+    let creator_key = vault.creator;
+    let vault_seed = vault.vault_seed;
+    let bump = vault.bump;
+
+    let seeds = &[
+        VAULT_SEED,
+        creator_key.as_ref(),
+        &vault_seed.to_le_bytes(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if record.kind == CustodiedAuthority::ProgramUpgrade {
+        let (Some(program_data), Some(_)) = (program_data, bpf_loader) else {
+            return err!(VaultError::CustodyAccountsRequired);
+        };
+        require_keys_eq!(
+            program_data.key(),
+            get_program_data_address(&record.target),
+            VaultError::CustodyAccountsRequired
+        );
+
+        invoke_signed(
+            &set_upgrade_authority(&record.target, &vault.key(), Some(new_authority.key)),
+            &[
+                program_data.to_account_info(),
+                vault.to_account_info(),
+                new_authority.clone(),
+            ],
+            signer_seeds,
+        )?;
+
+        return Ok(());
+    }
+
+    let (Some(mint), Some(token_program)) = (mint, token_program) else {
+        return err!(VaultError::CustodyAccountsRequired);
+    };
+    require_keys_eq!(mint.key(), record.target, VaultError::InvalidMint);
+
+    let authority_type = if record.kind == CustodiedAuthority::MintTokens {
+        AuthorityType::MintTokens
+    } else {
+        AuthorityType::FreezeAccount
+    };
+
+    token::set_authority(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            SetAuthority {
+                current_authority: vault.to_account_info(),
+                account_or_mint: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        authority_type,
+        Some(new_authority.key()),
+    )
+}
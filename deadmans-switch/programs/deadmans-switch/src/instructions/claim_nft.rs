@@ -0,0 +1,256 @@
+//! Claim one locked NFT from a released vault.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{mpl_token_metadata::instructions::TransferV1CpiBuilder, Metadata},
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct ClaimNft<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The recipient, or the owner if the vault was destroyed on expiry
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(address = nft_record.mint @ VaultError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Record of the NFT being claimed; closed to the claimant
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [NFT_SEED, vault.key().as_ref(), mint.key().as_ref()],
+        bump = nft_record.bump,
+    )]
+    pub nft_record: Account<'info, NftRecord>,
+
+    /// Vault's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Claimant's token account (destination)
+    /// Will be created if doesn't exist
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        associated_token::mint = mint,
+        associated_token::authority = claimant,
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Token Metadata account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Master edition (programmable NFTs only), verified by Token Metadata
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token record of the vault's token account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub vault_token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token record of the claimant's token account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub claimant_token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rule set named by the NFT's programmable config, verified by Token Metadata
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Auth Rules program (only with a rule set), verified by Token Metadata
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+
+    /// CHECK: Instructions sysvar, required by Token Metadata for programmable transfers
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimNft<'info> {
+    /// Handler for claim_nft instruction.
+    /// Moves a single NFT from the vault to the claimant. Vaults destroyed on
+    /// expiry hand their NFTs back to the owner instead of the recipient.
+    pub fn handler(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        self.vault.require_claim_open(clock.unix_timestamp)?;
+
+        let heir = if self.vault.release_action == ReleaseAction::Release {
+            self.vault.recipient
+        } else {
+            self.vault.owner
+        };
+        require_keys_eq!(self.claimant.key(), heir, VaultError::NotRecipient);
+
+        transfer_nft_out(NftTransferOut {
+            vault: &self.vault,
+            record: &self.nft_record,
+            mint: &self.mint,
+            vault_token_account: &mut self.vault_token_account,
+            destination_token_account: self.claimant_token_account.to_account_info(),
+            destination_owner: self.claimant.to_account_info(),
+            payer: self.claimant.to_account_info(),
+            metadata: self.metadata.as_ref(),
+            edition: self.edition.as_ref(),
+            vault_token_record: self.vault_token_record.as_ref(),
+            destination_token_record: self.claimant_token_record.as_ref(),
+            authorization_rules: self.authorization_rules.as_ref(),
+            authorization_rules_program: self.authorization_rules_program.as_ref(),
+            token_metadata_program: self.token_metadata_program.as_ref(),
+            sysvar_instructions: self.sysvar_instructions.as_ref(),
+            token_program: &self.token_program,
+            associated_token_program: &self.associated_token_program,
+            system_program: &self.system_program,
+        })?;
+
+        let vault = &mut self.vault;
+        vault.locked_nfts -= 1;
+
+        msg!(
+            "Claimed NFT {} to {} ({} still locked)",
+            self.mint.key(),
+            self.claimant.key(),
+            vault.locked_nfts
+        );
+
+        Ok(())
+    }
+}
+
+/// Accounts needed to move a locked NFT out of the vault PDA.
+pub(crate) struct NftTransferOut<'a, 'info> {
+    pub vault: &'a Account<'info, Vault>,
+    pub record: &'a NftRecord,
+    pub mint: &'a Account<'info, Mint>,
+    pub vault_token_account: &'a mut Account<'info, TokenAccount>,
+    pub destination_token_account: AccountInfo<'info>,
+    pub destination_owner: AccountInfo<'info>,
+    /// Pays for Token Metadata's destination token record and receives the emptied token account's rent
+    pub payer: AccountInfo<'info>,
+    pub metadata: Option<&'a UncheckedAccount<'info>>,
+    pub edition: Option<&'a UncheckedAccount<'info>>,
+    pub vault_token_record: Option<&'a UncheckedAccount<'info>>,
+    pub destination_token_record: Option<&'a UncheckedAccount<'info>>,
+    pub authorization_rules: Option<&'a UncheckedAccount<'info>>,
+    pub authorization_rules_program: Option<&'a UncheckedAccount<'info>>,
+    pub token_metadata_program: Option<&'a Program<'info, Metadata>>,
+    pub sysvar_instructions: Option<&'a UncheckedAccount<'info>>,
+    pub token_program: &'a Program<'info, Token>,
+    pub associated_token_program: &'a Program<'info, AssociatedToken>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+/// Move a locked NFT from the vault's token account to the destination, then close
+/// the emptied vault token account unless Token Metadata already closed (or froze) it.
+pub(crate) fn transfer_nft_out(out: NftTransferOut) -> Result<()> {
+    // PDA seeds for signing
+    let creator_key = out.vault.creator;
+    let vault_seed = out.vault.vault_seed;
+    let bump = out.vault.bump;
+
+    let seeds = &[
+        VAULT_SEED,
+        creator_key.as_ref(),
+        &vault_seed.to_le_bytes(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if out.record.is_programmable {
+        let (
+            Some(metadata),
+            Some(edition),
+            Some(vault_token_record),
+            Some(destination_token_record),
+            Some(token_metadata_program),
+            Some(sysvar_instructions),
+        ) = (
+            out.metadata,
+            out.edition,
+            out.vault_token_record,
+            out.destination_token_record,
+            out.token_metadata_program,
+            out.sysvar_instructions,
+        ) else {
+            return err!(VaultError::ProgrammableAccountsRequired);
+        };
+
+        let mint = out.mint.to_account_info();
+        let vault = out.vault.to_account_info();
+        let vault_token_account = out.vault_token_account.to_account_info();
+        let system_program = out.system_program.to_account_info();
+        let token_program = out.token_program.to_account_info();
+        let associated_token_program = out.associated_token_program.to_account_info();
+        let token_metadata_program = token_metadata_program.to_account_info();
+
+        TransferV1CpiBuilder::new(&token_metadata_program)
+            .token(&vault_token_account)
+            .token_owner(&vault)
+            .destination_token(&out.destination_token_account)
+            .destination_owner(&out.destination_owner)
+            .mint(&mint)
+            .metadata(metadata)
+            .edition(Some(edition))
+            .token_record(Some(vault_token_record))
+            .destination_token_record(Some(destination_token_record))
+            .authority(&vault)
+            .payer(&out.payer)
+            .system_program(&system_program)
+            .sysvar_instructions(sysvar_instructions)
+            .spl_token_program(&token_program)
+            .spl_ata_program(&associated_token_program)
+            .authorization_rules_program(out.authorization_rules_program.map(|a| a.as_ref()))
+            .authorization_rules(out.authorization_rules.map(|a| a.as_ref()))
+            .amount(1)
+            .invoke_signed(signer_seeds)?;
+    } else {
+        token::transfer(
+            CpiContext::new_with_signer(
+                out.token_program.to_account_info(),
+                Transfer {
+                    from: out.vault_token_account.to_account_info(),
+                    to: out.destination_token_account,
+                    authority: out.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+    }
+
+    // Close the emptied vault token account, unless Token Metadata already
+    // closed it (or left it frozen) during a programmable transfer
+    if !out.vault_token_account.to_account_info().data_is_empty() {
+        out.vault_token_account.reload()?;
+        if out.vault_token_account.amount == 0 && !out.vault_token_account.is_frozen() {
+            token::close_account(
+                CpiContext::new_with_signer(
+                    out.token_program.to_account_info(),
+                    CloseAccount {
+                        account: out.vault_token_account.to_account_info(),
+                        destination: out.payer,
+                        authority: out.vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+            )?;
+        }
+    }
+
+    Ok(())
+}
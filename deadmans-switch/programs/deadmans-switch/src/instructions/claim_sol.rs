@@ -1,30 +1,95 @@
 //! Claim locked SOL from a released vault.
 
 use anchor_lang::prelude::*;
-use crate::{errors::*, state::*};
+use crate::{constants::*, errors::*, state::*};
+use super::settle_auto_stake::{settle_auto_stake, AutoStakeSettlement};
 
 #[derive(Accounts)]
 pub struct ClaimSol<'info> {
     #[account(
         mut,
-        constraint = vault.recipient == recipient.key() @ VaultError::NotRecipient,
         constraint = vault.is_released @ VaultError::NotReleased,
-        constraint = vault.locked_lamports > 0 @ VaultError::NoLockedSol,
+        constraint = vault.locked_lamports > 0 || vault.has_auto_stake @ VaultError::NoLockedSol,
     )]
     pub vault: Account<'info, Vault>,
 
+    /// Per-asset routing; required when the vault has an allocation table
+    #[account(
+        mut,
+        seeds = [ALLOCATION_SEED, vault.key().as_ref()],
+        bump = allocation_table.bump,
+    )]
+    pub allocation_table: Option<Account<'info, AllocationTable>>,
+
     #[account(mut)]
     pub recipient: Signer<'info>,
 
+    /// Auto-staked SOL to settle (required only for vaults with auto-staked SOL)
+    #[account(
+        mut,
+        seeds = [AUTO_STAKE_SEED, vault.key().as_ref()],
+        bump = auto_stake.bump,
+    )]
+    pub auto_stake: Option<Account<'info, AutoStake>>,
+
+    /// CHECK: The auto-stake target (native stake account or stake pool), checked against the AutoStake record
+    #[account(mut)]
+    pub stake_target: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Stake history sysvar (native stake only), verified by the stake program
+    pub stake_history: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Native stake program (native stake only)
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: Option<UncheckedAccount<'info>>,
+
+    pub clock: Option<Sysvar<'info, Clock>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ClaimSol<'info> {
     /// Handler for claim_sol instruction.
-    /// Transfers locked SOL from vault PDA to recipient.
+    /// Transfers locked SOL (or the recipient's allocated share of it) from vault PDA to recipient.
+    /// Auto-staked SOL is settled first; a delegated stake account is deactivated on the
+    /// first call and withdrawn back into the vault once the cooldown has passed.
     pub fn handler(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        self.vault.require_claim_open(clock.unix_timestamp)?;
+
+        // Authorize before settling, so only a claimant can unwind the stake or take its rent
+        self.vault
+            .require_claimant(self.allocation_table.as_deref(), None, &self.recipient.key())?;
+
+        if self.vault.has_auto_stake {
+            let settled = settle_auto_stake(
+                AutoStakeSettlement {
+                    vault: &mut self.vault,
+                    auto_stake: self.auto_stake.as_ref(),
+                    stake_target: self.stake_target.as_ref(),
+                    stake_history: self.stake_history.as_ref(),
+                    stake_program: self.stake_program.as_ref(),
+                    clock: self.clock.as_ref(),
+                    rent_destination: Some(self.recipient.to_account_info()),
+                },
+                &clock,
+            )?;
+            if !settled {
+                return Ok(());
+            }
+        }
+
         let vault = &mut self.vault;
-        let amount = vault.locked_lamports;
+        if vault.locked_lamports == 0 {
+            return Ok(());
+        }
+
+        let amount = vault.claimable_amount(
+            self.allocation_table.as_mut(),
+            None,
+            &self.recipient.key(),
+            vault.locked_lamports,
+        )?;
 
         // Transfer SOL from vault PDA to recipient
         // Vault PDA can transfer its own lamports without signing
@@ -32,7 +97,7 @@ impl<'info> ClaimSol<'info> {
         **self.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
 
         // Mark as claimed
-        vault.locked_lamports = 0;
+        vault.locked_lamports -= amount;
 
         msg!("Claimed {} lamports to recipient {}", amount, self.recipient.key());
 
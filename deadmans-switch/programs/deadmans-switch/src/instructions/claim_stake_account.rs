@@ -0,0 +1,93 @@
+//! Claim a stake account's authorities from a released vault.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+use super::lock_stake_account::authorize_stake_account;
+
+#[derive(Accounts)]
+pub struct ClaimStakeAccount<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The recipient, or the owner if the vault was destroyed on expiry
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Native stake account named by the stake record
+    #[account(mut, address = stake_record.stake_account @ VaultError::InvalidStakeAccount)]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// Record of the stake account being claimed; closed to the claimant
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [STAKE_SEED, vault.key().as_ref(), stake_account.key().as_ref()],
+        bump = stake_record.bump,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    /// Lockup custodian; must co-sign while the stake account's lockup is in force
+    pub custodian: Option<Signer<'info>>,
+
+    /// CHECK: Native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ClaimStakeAccount<'info> {
+    /// Handler for claim_stake_account instruction.
+    /// Hands the staker and withdrawer authorities to the claimant. Vaults destroyed
+    /// on expiry hand their stake accounts back to the owner instead of the recipient.
+    pub fn handler(&mut self) -> Result<()> {
+        self.vault.require_claim_open(self.clock.unix_timestamp)?;
+
+        let heir = if self.vault.release_action == ReleaseAction::Release {
+            self.vault.recipient
+        } else {
+            self.vault.owner
+        };
+        require_keys_eq!(self.claimant.key(), heir, VaultError::NotRecipient);
+
+        let meta = StakeRecord::stake_meta(&self.stake_account)?;
+        let custodian = self.custodian.as_ref().map(|custodian| custodian.to_account_info());
+        let custodian = StakeRecord::lockup_custodian(&meta, custodian.as_ref(), &self.clock)?;
+
+        // PDA seeds for signing
+        let creator_key = self.vault.creator;
+        let vault_seed = self.vault.vault_seed;
+        let bump = self.vault.bump;
+
+        let seeds = &[
+            VAULT_SEED,
+            creator_key.as_ref(),
+            &vault_seed.to_le_bytes(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        authorize_stake_account(
+            &self.stake_account,
+            &self.vault.to_account_info(),
+            &self.clock.to_account_info(),
+            &heir,
+            custodian,
+            signer_seeds,
+        )?;
+
+        let vault = &mut self.vault;
+        vault.locked_stake_accounts -= 1;
+
+        msg!(
+            "Stake account {} claimed by {}",
+            self.stake_account.key(),
+            self.claimant.key()
+        );
+
+        Ok(())
+    }
+}
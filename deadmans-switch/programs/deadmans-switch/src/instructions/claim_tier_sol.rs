@@ -0,0 +1,53 @@
+//! Claim the SOL slice of a released escalation tier.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct ClaimTierSol<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [ESCALATION_SEED, vault.key().as_ref()],
+        bump = escalation_plan.bump,
+    )]
+    pub escalation_plan: Account<'info, EscalationPlan>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimTierSol<'info> {
+    /// Handler for claim_tier_sol instruction.
+    /// Transfers the tier's SOL slice from vault PDA to its beneficiary.
+    pub fn handler(&mut self, tier_index: u8) -> Result<()> {
+        let tier = self
+            .escalation_plan
+            .tiers
+            .get_mut(tier_index as usize)
+            .ok_or(VaultError::InvalidTierIndex)?;
+
+        require_keys_eq!(tier.beneficiary, self.beneficiary.key(), VaultError::NotTierBeneficiary);
+        tier.require_claim_open(Clock::get()?.unix_timestamp)?;
+        require!(tier.lamports > 0, VaultError::NoLockedSol);
+
+        let amount = tier.lamports;
+        **self.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **self.beneficiary.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        tier.lamports = 0;
+
+        msg!(
+            "Claimed {} lamports from tier {} to beneficiary {}",
+            amount,
+            tier_index,
+            self.beneficiary.key()
+        );
+
+        Ok(())
+    }
+}
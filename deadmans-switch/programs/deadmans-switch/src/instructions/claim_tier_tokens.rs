@@ -0,0 +1,109 @@
+//! Claim the SPL token slice of a released escalation tier.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct ClaimTierTokens<'info> {
+    #[account(
+        constraint = vault.token_mint.is_some() @ VaultError::NoTokensLocked,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [ESCALATION_SEED, vault.key().as_ref()],
+        bump = escalation_plan.bump,
+    )]
+    pub escalation_plan: Account<'info, EscalationPlan>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        constraint = token_mint.key() == vault.token_mint.unwrap() @ VaultError::InvalidMint,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Vault's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Beneficiary's token account (destination)
+    /// Will be created if doesn't exist
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = token_mint,
+        associated_token::authority = beneficiary,
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimTierTokens<'info> {
+    /// Handler for claim_tier_tokens instruction.
+    /// The vault token account stays open for the main recipient and other tiers.
+    pub fn handler(&mut self, tier_index: u8) -> Result<()> {
+        let vault = &self.vault;
+        let tier = self
+            .escalation_plan
+            .tiers
+            .get_mut(tier_index as usize)
+            .ok_or(VaultError::InvalidTierIndex)?;
+
+        require_keys_eq!(tier.beneficiary, self.beneficiary.key(), VaultError::NotTierBeneficiary);
+        tier.require_claim_open(Clock::get()?.unix_timestamp)?;
+        require!(tier.tokens > 0, VaultError::TokensAlreadyClaimed);
+
+        let amount = tier.tokens;
+
+        // PDA seeds for signing
+        let creator_key = vault.creator;
+        let vault_seed = vault.vault_seed;
+        let bump = vault.bump;
+
+        let seeds = &[
+            VAULT_SEED,
+            creator_key.as_ref(),
+            &vault_seed.to_le_bytes(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.vault_token_account.to_account_info(),
+                    to: self.beneficiary_token_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        tier.tokens = 0;
+
+        msg!(
+            "Claimed {} tokens from tier {} to beneficiary {}",
+            amount,
+            tier_index,
+            self.beneficiary.key()
+        );
+
+        Ok(())
+    }
+}
@@ -11,13 +11,21 @@ use crate::{constants::*, errors::*, state::*};
 pub struct ClaimTokens<'info> {
     #[account(
         mut,
-        constraint = vault.recipient == recipient.key() @ VaultError::NotRecipient,
         constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = !vault.has_auto_stake @ VaultError::AutoStakeActive,
         constraint = vault.token_mint.is_some() @ VaultError::NoTokensLocked,
         constraint = vault.locked_tokens > 0 @ VaultError::TokensAlreadyClaimed,
     )]
     pub vault: Account<'info, Vault>,
 
+    /// Per-asset routing; required when the vault has an allocation table
+    #[account(
+        mut,
+        seeds = [ALLOCATION_SEED, vault.key().as_ref()],
+        bump = allocation_table.bump,
+    )]
+    pub allocation_table: Option<Account<'info, AllocationTable>>,
+
     #[account(mut)]
     pub recipient: Signer<'info>,
 
@@ -51,19 +59,28 @@ pub struct ClaimTokens<'info> {
 
 impl<'info> ClaimTokens<'info> {
     /// Handler for claim_tokens instruction.
-    /// Transfers SPL tokens from vault to recipient and closes vault's token account.
+    /// Transfers SPL tokens (or the recipient's allocated share of them) from vault
+    /// to recipient and closes vault's token account once empty.
     pub fn handler(&mut self) -> Result<()> {
         let vault = &mut self.vault;
-        let amount = vault.locked_tokens;
+        let clock = Clock::get()?;
+        vault.require_claim_open(clock.unix_timestamp)?;
+
+        let amount = vault.claimable_amount(
+            self.allocation_table.as_mut(),
+            vault.token_mint,
+            &self.recipient.key(),
+            vault.locked_tokens,
+        )?;
 
         // PDA seeds for signing
-        let owner_key = vault.owner;
+        let creator_key = vault.creator;
         let vault_seed = vault.vault_seed;
         let bump = vault.bump;
         
         let seeds = &[
             VAULT_SEED,
-            owner_key.as_ref(),
+            creator_key.as_ref(),
             &vault_seed.to_le_bytes(),
             &[bump],
         ];
@@ -83,21 +100,24 @@ impl<'info> ClaimTokens<'info> {
             amount,
         )?;
 
-        // Close vault token account and reclaim rent to recipient
-        token::close_account(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                CloseAccount {
-                    account: self.vault_token_account.to_account_info(),
-                    destination: self.recipient.to_account_info(),
-                    authority: vault.to_account_info(),
-                },
-                signer_seeds,
-            ),
-        )?;
+        // Close vault token account once empty; escalation tiers or other shares may still hold some
+        self.vault_token_account.reload()?;
+        if self.vault_token_account.amount == 0 {
+            token::close_account(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    CloseAccount {
+                        account: self.vault_token_account.to_account_info(),
+                        destination: self.recipient.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+            )?;
+        }
 
         // Mark as claimed
-        vault.locked_tokens = 0;
+        vault.locked_tokens -= amount;
 
         msg!(
             "Claimed {} tokens to recipient {}",
@@ -0,0 +1,25 @@
+//! Close a proposal (executed or abandoned) and reclaim rent to the proposer.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer @ VaultError::Unauthorized
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+impl<'info> CloseProposal<'info> {
+    /// Handler for close_proposal instruction.
+    pub fn handler(&self) -> Result<()> {
+        msg!("Proposal {} closed. Rent reclaimed.", self.proposal.nonce);
+        Ok(())
+    }
+}
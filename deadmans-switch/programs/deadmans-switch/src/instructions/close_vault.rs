@@ -1,24 +1,106 @@
 //! Close the vault and reclaim rent back to owner.
 
 use anchor_lang::prelude::*;
-use crate::{errors::*, state::*};
+use crate::{constants::*, errors::*, state::*};
 
 #[derive(Accounts)]
 pub struct CloseVault<'info> {
     #[account(
         mut,
         close = owner,
-        has_one = owner @ VaultError::Unauthorized
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = vault.locked_nfts == 0 @ VaultError::NftsStillLocked,
+        constraint = vault.locked_stake_accounts == 0 @ VaultError::StakeAccountsStillLocked,
+        constraint = !vault.has_auto_stake @ VaultError::AutoStakeActive,
+        constraint = vault.custodied_authorities == 0 @ VaultError::AuthoritiesStillCustodied,
     )]
     pub vault: Account<'info, Vault>,
 
     #[account(mut)]
     pub owner: Signer<'info>,
+
+    /// Estate the vault is linked to (required only for linked vaults)
+    #[account(mut, constraint = vault.estate == Some(estate.key()) @ VaultError::EstateMismatch)]
+    pub estate: Option<Account<'info, Estate>>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    /// Escalation plan (required only for vaults with staged tiers); closed with the vault
+    #[account(
+        mut,
+        close = owner,
+        seeds = [ESCALATION_SEED, vault.key().as_ref()],
+        bump = escalation_plan.bump,
+    )]
+    pub escalation_plan: Option<Account<'info, EscalationPlan>>,
+
+    /// Allocation table (required only for vaults routing assets by share); closed with the vault
+    #[account(
+        mut,
+        close = owner,
+        seeds = [ALLOCATION_SEED, vault.key().as_ref()],
+        bump = allocation_table.bump,
+    )]
+    pub allocation_table: Option<Account<'info, AllocationTable>>,
+
+    /// Recovery guardians (required only once guardians were registered); closed with the vault
+    #[account(
+        mut,
+        close = owner,
+        seeds = [RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+    )]
+    pub recovery: Option<Account<'info, Recovery>>,
+
+    /// Legacy plan (required only while steps are stored); closed with the vault
+    #[account(
+        mut,
+        close = owner,
+        seeds = [LEGACY_SEED, vault.key().as_ref()],
+        bump = legacy_plan.bump,
+    )]
+    pub legacy_plan: Option<Account<'info, LegacyPlan>>,
 }
 
 impl<'info> CloseVault<'info> {
     /// Handler for close_vault instruction.
-    pub fn handler(&self) -> Result<()> {
+    pub fn handler(&mut self, co_signers: &[AccountInfo]) -> Result<()> {
+        self.vault.require_revocable()?;
+        self.vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::CloseVault,
+            &(),
+        )?;
+
+        // Side accounts must go with the vault, or a vault re-created under the
+        // same seed would pick up their stale contents
+        let vault = &self.vault;
+        require!(
+            !vault.has_escalation_plan || self.escalation_plan.is_some(),
+            VaultError::EscalationPlanRequired
+        );
+        require!(
+            !vault.has_allocation_table || self.allocation_table.is_some(),
+            VaultError::AllocationTableRequired
+        );
+        require!(
+            !vault.has_recovery || self.recovery.is_some(),
+            VaultError::RecoveryRequired
+        );
+        require!(
+            !vault.has_legacy_plan || self.legacy_plan.is_some(),
+            VaultError::LegacyPlanRequired
+        );
+
+        if self.vault.estate.is_some() {
+            let estate = self.estate.as_mut().ok_or(VaultError::EstateMismatch)?;
+            estate.linked_vaults = estate.linked_vaults.saturating_sub(1);
+        }
+
         msg!("Vault closed by owner. Rent reclaimed.");
         Ok(())
     }
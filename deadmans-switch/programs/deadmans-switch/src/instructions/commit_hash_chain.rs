@@ -0,0 +1,60 @@
+//! Commit (or clear) the proof-of-life hash chain that check-ins must follow.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct CommitHashChain<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> CommitHashChain<'info> {
+    /// Handler for commit_hash_chain instruction.
+    /// `head` is the last hash of a chain of `length` preimages kept offline; send it
+    /// alongside initialize_vault to protect the vault from creation. `None` turns
+    /// hash-chain pings off.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        head: Option<[u8; 32]>,
+        length: u32,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::CommitHashChain,
+            &(head, length),
+        )?;
+
+        match head {
+            Some(_) => {
+                require!(length > 0, VaultError::InvalidHashChain);
+                // An estate ping would extend the vault without a preimage
+                require!(vault.estate.is_none(), VaultError::AlreadyLinked);
+                vault.hash_chain_remaining = length;
+                msg!("Hash chain committed with {} pings", length);
+            }
+            None => {
+                vault.hash_chain_remaining = 0;
+                msg!("Hash chain cleared");
+            }
+        }
+        vault.hash_chain_head = head;
+
+        Ok(())
+    }
+}
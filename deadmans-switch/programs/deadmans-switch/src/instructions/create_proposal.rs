@@ -0,0 +1,56 @@
+//! Propose an administrative action for other admin keys to approve.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct CreateProposal<'info> {
+    #[account(
+        constraint = vault.is_admin_key(&proposer.key()) @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::SPACE,
+        seeds = [PROPOSAL_SEED, vault.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateProposal<'info> {
+    /// Handler for create_proposal instruction.
+    /// The proposer's approval is recorded immediately.
+    pub fn handler(
+        &mut self,
+        nonce: u64,
+        action: AdminAction,
+        args_hash: [u8; 32],
+        bump: u8,
+    ) -> Result<()> {
+        let proposal = &mut self.proposal;
+
+        proposal.vault = self.vault.key();
+        proposal.proposer = self.proposer.key();
+        proposal.action = action;
+        proposal.args_hash = args_hash;
+        proposal.approvals = vec![self.proposer.key()];
+        proposal.executed = false;
+        proposal.nonce = nonce;
+        proposal.bump = bump;
+
+        msg!("Proposal {} created for vault {}", nonce, proposal.vault);
+        msg!("Action: {:?}", action);
+
+        Ok(())
+    }
+}
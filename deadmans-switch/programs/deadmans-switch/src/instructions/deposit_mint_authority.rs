@@ -0,0 +1,80 @@
+//! Move an SPL mint or freeze authority into the vault's custody.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, SetAuthority, Token};
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(kind: CustodiedAuthority)]
+pub struct DepositMintAuthority<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Current holder of the authority
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    /// Record of this authority in the vault (one per mint and kind)
+    #[account(
+        init,
+        payer = owner,
+        space = AuthorityRecord::SPACE,
+        seeds = [AUTHORITY_SEED, vault.key().as_ref(), mint.key().as_ref(), &[kind as u8]],
+        bump,
+    )]
+    pub authority_record: Account<'info, AuthorityRecord>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositMintAuthority<'info> {
+    /// Handler for deposit_mint_authority instruction.
+    /// Reassigns the mint's mint or freeze authority from the owner to the vault PDA.
+    pub fn handler(&mut self, kind: CustodiedAuthority, record_bump: u8) -> Result<()> {
+        let (current, authority_type) = match kind {
+            CustodiedAuthority::MintTokens => (self.mint.mint_authority, AuthorityType::MintTokens),
+            CustodiedAuthority::FreezeAccount => (self.mint.freeze_authority, AuthorityType::FreezeAccount),
+            CustodiedAuthority::ProgramUpgrade => return err!(VaultError::InvalidAuthorityKind),
+        };
+        require!(
+            Option::<Pubkey>::from(current) == Some(self.owner.key()),
+            VaultError::AuthorityNotHeld
+        );
+
+        token::set_authority(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: self.owner.to_account_info(),
+                    account_or_mint: self.mint.to_account_info(),
+                },
+            ),
+            authority_type,
+            Some(self.vault.key()),
+        )?;
+
+        let record = &mut self.authority_record;
+        record.vault = self.vault.key();
+        record.target = self.mint.key();
+        record.kind = kind;
+        record.bump = record_bump;
+
+        let vault = &mut self.vault;
+        vault.custodied_authorities = vault
+            .custodied_authorities
+            .checked_add(1)
+            .ok_or(VaultError::Overflow)?;
+
+        msg!("{:?} authority of mint {} now held by vault", kind, self.mint.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+//! Move a BPF upgradeable program's upgrade authority into the vault's custody.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use solana_loader_v3_interface::{get_program_data_address, instruction::set_upgrade_authority};
+use solana_sdk_ids::bpf_loader_upgradeable;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct DepositUpgradeAuthority<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Current upgrade authority of the program
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The upgradeable program; its program data address is derived from it
+    pub program: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = get_program_data_address(&program.key()),
+        constraint = program_data.upgrade_authority_address == Some(owner.key()) @ VaultError::AuthorityNotHeld,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Record of this authority in the vault
+    #[account(
+        init,
+        payer = owner,
+        space = AuthorityRecord::SPACE,
+        seeds = [
+            AUTHORITY_SEED,
+            vault.key().as_ref(),
+            program.key().as_ref(),
+            &[CustodiedAuthority::ProgramUpgrade as u8],
+        ],
+        bump,
+    )]
+    pub authority_record: Account<'info, AuthorityRecord>,
+
+    /// CHECK: BPF upgradeable loader; the set-authority instruction targets it by ID
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositUpgradeAuthority<'info> {
+    /// Handler for deposit_upgrade_authority instruction.
+    /// Reassigns the program's upgrade authority from the owner to the vault PDA.
+    pub fn handler(&mut self, record_bump: u8) -> Result<()> {
+        invoke(
+            &set_upgrade_authority(&self.program.key(), &self.owner.key(), Some(&self.vault.key())),
+            &[
+                self.program_data.to_account_info(),
+                self.owner.to_account_info(),
+                self.vault.to_account_info(),
+            ],
+        )?;
+
+        let record = &mut self.authority_record;
+        record.vault = self.vault.key();
+        record.target = self.program.key();
+        record.kind = CustodiedAuthority::ProgramUpgrade;
+        record.bump = record_bump;
+
+        let vault = &mut self.vault;
+        vault.custodied_authorities = vault
+            .custodied_authorities
+            .checked_add(1)
+            .ok_or(VaultError::Overflow)?;
+
+        msg!("Upgrade authority of program {} now held by vault", self.program.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+//! Execute the next stored legacy instruction of a released vault.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use crate::{constants::*, errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct ExecuteLegacyStep<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::VaultDestroyed,
+        constraint = vault.has_legacy_plan @ VaultError::NoPendingLegacySteps,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [LEGACY_SEED, vault.key().as_ref()],
+        bump = legacy_plan.bump,
+    )]
+    pub legacy_plan: Account<'info, LegacyPlan>,
+
+    /// Anyone may execute the steps once the vault is released
+    pub executor: Signer<'info>,
+}
+
+impl<'info> ExecuteLegacyStep<'info> {
+    /// Handler for execute_legacy_step instruction.
+    /// Remaining accounts must start with the step's accounts in stored order,
+    /// followed by the program it calls.
+    pub fn handler(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let clock = Clock::get()?;
+        let plan = &mut self.legacy_plan;
+        let index = plan.next_step as usize;
+        let step = plan.steps.get(index).ok_or(VaultError::NoPendingLegacySteps)?;
+
+        require!(
+            remaining_accounts.len() > step.accounts.len(),
+            VaultError::LegacyAccountMismatch
+        );
+        for (meta, account) in step.accounts.iter().zip(remaining_accounts) {
+            require_keys_eq!(account.key(), meta.pubkey, VaultError::LegacyAccountMismatch);
+        }
+        require_keys_eq!(
+            remaining_accounts[step.accounts.len()].key(),
+            step.program_id,
+            VaultError::LegacyAccountMismatch
+        );
+
+        let instruction = Instruction {
+            program_id: step.program_id,
+            accounts: step
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: step.data.clone(),
+        };
+        let program_id = step.program_id;
+
+        // PDA seeds for signing
+        let creator_key = self.vault.creator;
+        let vault_seed = self.vault.vault_seed;
+        let bump = self.vault.bump;
+
+        let seeds = &[
+            VAULT_SEED,
+            creator_key.as_ref(),
+            &vault_seed.to_le_bytes(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        invoke_signed(&instruction, remaining_accounts, signer_seeds)?;
+
+        plan.steps[index].executed_at = clock.unix_timestamp;
+        plan.next_step += 1;
+
+        let vault = &mut self.vault;
+        if plan.next_step as usize == plan.steps.len() {
+            vault.has_legacy_plan = false;
+        }
+
+        emit!(LegacyStepExecuted {
+            vault: vault.key(),
+            step_index: index as u8,
+            program_id,
+            executor: self.executor.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Legacy step {} executed", index);
+
+        Ok(())
+    }
+}
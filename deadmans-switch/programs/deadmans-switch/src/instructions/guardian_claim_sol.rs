@@ -0,0 +1,49 @@
+//! Claim locked SOL on a minor recipient's behalf into the restricted destination.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct GuardianClaimSol<'info> {
+    #[account(
+        mut,
+        constraint = vault.minor_guardian == Some(guardian.key()) @ VaultError::NotMinorGuardian,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = !vault.has_auto_stake @ VaultError::AutoStakeActive,
+        constraint = !vault.has_allocation_table @ VaultError::AllocationTableActive,
+        constraint = vault.locked_lamports > 0 @ VaultError::NoLockedSol,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub guardian: Signer<'info>,
+
+    /// CHECK: Only receives lamports; must be the vault's guardian destination
+    #[account(
+        mut,
+        constraint = vault.guardian_destination == Some(destination.key()) @ VaultError::InvalidDestination,
+    )]
+    pub destination: UncheckedAccount<'info>,
+}
+
+impl<'info> GuardianClaimSol<'info> {
+    /// Handler for guardian_claim_sol instruction.
+    /// Not subject to the claim-not-before date; funds can only reach the restricted destination.
+    pub fn handler(&mut self) -> Result<()> {
+        let vault = &mut self.vault;
+        let amount = vault.locked_lamports;
+
+        **vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **self.destination.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        vault.locked_lamports = 0;
+
+        msg!(
+            "Guardian {} claimed {} lamports into {}",
+            self.guardian.key(),
+            amount,
+            self.destination.key()
+        );
+
+        Ok(())
+    }
+}
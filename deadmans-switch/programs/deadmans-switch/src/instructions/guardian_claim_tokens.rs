@@ -0,0 +1,121 @@
+//! Claim locked SPL tokens on a minor recipient's behalf into the restricted destination.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct GuardianClaimTokens<'info> {
+    #[account(
+        mut,
+        constraint = vault.minor_guardian == Some(guardian.key()) @ VaultError::NotMinorGuardian,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = !vault.has_auto_stake @ VaultError::AutoStakeActive,
+        constraint = !vault.has_allocation_table @ VaultError::AllocationTableActive,
+        constraint = vault.token_mint.is_some() @ VaultError::NoTokensLocked,
+        constraint = vault.locked_tokens > 0 @ VaultError::TokensAlreadyClaimed,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// CHECK: Owner of the destination token account; must be the vault's guardian destination
+    #[account(
+        mut,
+        constraint = vault.guardian_destination == Some(destination.key()) @ VaultError::InvalidDestination,
+    )]
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = token_mint.key() == vault.token_mint.unwrap() @ VaultError::InvalidMint,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Vault's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Destination's token account
+    /// Will be created if doesn't exist
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        associated_token::mint = token_mint,
+        associated_token::authority = destination,
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GuardianClaimTokens<'info> {
+    /// Handler for guardian_claim_tokens instruction.
+    /// Transfers SPL tokens to the guardian destination and closes vault's token account.
+    pub fn handler(&mut self) -> Result<()> {
+        let vault = &mut self.vault;
+        let amount = vault.locked_tokens;
+
+        // PDA seeds for signing
+        let creator_key = vault.creator;
+        let vault_seed = vault.vault_seed;
+        let bump = vault.bump;
+
+        let seeds = &[
+            VAULT_SEED,
+            creator_key.as_ref(),
+            &vault_seed.to_le_bytes(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.vault_token_account.to_account_info(),
+                    to: self.destination_token_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        // Close vault token account once empty; escalation tiers may still hold a slice
+        self.vault_token_account.reload()?;
+        if self.vault_token_account.amount == 0 {
+            token::close_account(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    CloseAccount {
+                        account: self.vault_token_account.to_account_info(),
+                        destination: self.destination.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+            )?;
+        }
+
+        vault.locked_tokens = 0;
+
+        msg!(
+            "Guardian {} claimed {} tokens into {}",
+            self.guardian.key(),
+            amount,
+            self.destination.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+//! Initialize an estate: a shared heartbeat that linked vaults follow.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeEstate<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = Estate::SPACE,
+        seeds = [ESTATE_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub estate: Account<'info, Estate>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeEstate<'info> {
+    /// Handler for initialize_estate instruction.
+    pub fn handler(&mut self, time_interval: i64, bump: u8) -> Result<()> {
+        require!(time_interval > 0, VaultError::InvalidTimeInterval);
+
+        let estate = &mut self.estate;
+        let clock = Clock::get()?;
+
+        estate.owner = self.owner.key();
+        estate.time_interval = time_interval;
+        estate.last_check_in = clock.unix_timestamp;
+        estate.linked_vaults = 0;
+        estate.bump = bump;
+
+        msg!("Estate initialized for owner: {}", estate.owner);
+        msg!("Time interval: {} seconds", time_interval);
+
+        Ok(())
+    }
+}
@@ -50,6 +50,7 @@ impl<'info> InitializeVault<'info> {
         let clock = Clock::get()?;
 
         vault.owner = self.owner.key();
+        vault.creator = self.owner.key();
         vault.recipient = recipient;
         vault.ipfs_cid = ipfs_cid;
         vault.encrypted_key = encrypted_key;
@@ -64,6 +65,42 @@ impl<'info> InitializeVault<'info> {
         vault.locked_lamports = locked_lamports;
         vault.token_mint = None;      // T.2: No tokens locked initially
         vault.locked_tokens = 0;      // T.2: Tokens locked via separate instruction
+        vault.estate = None;
+        vault.co_owners = Vec::new();
+        vault.survivor_policy = SurvivorPolicy::All;
+        vault.admin_threshold = 1;
+        vault.authorities = Vec::new();
+        vault.snoozed_until = 0;
+        vault.max_snooze = 0;
+        vault.release_mode = ReleaseMode::Heartbeat;
+        vault.release_at = 0;
+        vault.claim_not_before = 0;
+        vault.minor_guardian = None;
+        vault.guardian_destination = None;
+        vault.released_at = 0;
+        vault.claim_deadline = 0;
+        vault.fallback_recipients = Vec::new();
+        vault.custody_log = Vec::new();
+        vault.has_escalation_plan = false;
+        vault.has_allocation_table = false;
+        vault.is_irrevocable = false;
+        vault.release_action = ReleaseAction::Release;
+        vault.ping_nonce = 0;
+        vault.hash_chain_head = None;
+        vault.hash_chain_remaining = 0;
+        vault.liveness_adapters = Vec::new();
+        vault.warning_window = 0;
+        vault.warned_at = 0;
+        vault.notification_bounty = 0;
+        vault.notification_pool = 0;
+        vault.release_notice_lamports = 0;
+        vault.release_notice_memo = String::new();
+        vault.locked_nfts = 0;
+        vault.locked_stake_accounts = 0;
+        vault.has_auto_stake = false;
+        vault.custodied_authorities = 0;
+        vault.has_legacy_plan = false;
+        vault.has_recovery = false;
 
         // Transfer bounty + locked SOL from owner to vault PDA
         let total_transfer = bounty_lamports
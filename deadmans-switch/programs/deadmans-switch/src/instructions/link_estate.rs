@@ -0,0 +1,41 @@
+//! Link a vault to an estate so it follows the shared heartbeat.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct LinkEstate<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+        constraint = vault.estate.is_none() @ VaultError::AlreadyLinked,
+        constraint = vault.hash_chain_head.is_none() @ VaultError::PreimageRequired,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [ESTATE_SEED, owner.key().as_ref()],
+        bump = estate.bump,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub estate: Account<'info, Estate>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> LinkEstate<'info> {
+    /// Handler for link_estate instruction.
+    pub fn handler(&mut self) -> Result<()> {
+        let vault = &mut self.vault;
+        let estate = &mut self.estate;
+
+        vault.estate = Some(estate.key());
+        estate.linked_vaults = estate.linked_vaults.checked_add(1).ok_or(VaultError::Overflow)?;
+
+        msg!("Vault '{}' linked to estate {}", vault.name, estate.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+//! Lock an NFT (standard or Metaplex programmable) into a vault.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{
+        mpl_token_metadata::{instructions::TransferV1CpiBuilder, types::TokenStandard},
+        Metadata, MetadataAccount,
+    },
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct LockNft<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = mint.decimals == 0 && mint.supply == 1 @ VaultError::NotAnNft,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// Token Metadata account of the NFT
+    #[account(
+        mut,
+        constraint = metadata.mint == mint.key() @ VaultError::InvalidMint,
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+
+    /// Record of this NFT in the vault (one per mint)
+    #[account(
+        init,
+        payer = owner,
+        space = NftRecord::SPACE,
+        seeds = [NFT_SEED, vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub nft_record: Account<'info, NftRecord>,
+
+    /// Owner's token account (source)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    /// Vault's token account (destination)
+    /// Will be created if doesn't exist
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Master edition (programmable NFTs only), verified by Token Metadata
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token record of the owner's token account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub owner_token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token record of the vault's token account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub vault_token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rule set named by the NFT's programmable config, verified by Token Metadata
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Auth Rules program (only with a rule set), verified by Token Metadata
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+
+    /// CHECK: Instructions sysvar, required by Token Metadata for programmable transfers
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LockNft<'info> {
+    /// Handler for lock_nft instruction.
+    /// Standard NFTs move with a plain SPL transfer; programmable NFTs are frozen
+    /// in place, so they move through a Token Metadata TransferV1 that enforces their rule set.
+    pub fn handler(&mut self, record_bump: u8) -> Result<()> {
+        let is_programmable = matches!(
+            self.metadata.token_standard,
+            Some(TokenStandard::ProgrammableNonFungible | TokenStandard::ProgrammableNonFungibleEdition)
+        );
+
+        if is_programmable {
+            let (
+                Some(edition),
+                Some(owner_token_record),
+                Some(vault_token_record),
+                Some(token_metadata_program),
+                Some(sysvar_instructions),
+            ) = (
+                self.edition.as_ref(),
+                self.owner_token_record.as_ref(),
+                self.vault_token_record.as_ref(),
+                self.token_metadata_program.as_ref(),
+                self.sysvar_instructions.as_ref(),
+            ) else {
+                return err!(VaultError::ProgrammableAccountsRequired);
+            };
+
+            let owner = self.owner.to_account_info();
+            let metadata = self.metadata.to_account_info();
+            let mint = self.mint.to_account_info();
+            let vault = self.vault.to_account_info();
+            let owner_token_account = self.owner_token_account.to_account_info();
+            let vault_token_account = self.vault_token_account.to_account_info();
+            let system_program = self.system_program.to_account_info();
+            let token_program = self.token_program.to_account_info();
+            let associated_token_program = self.associated_token_program.to_account_info();
+            let token_metadata_program = token_metadata_program.to_account_info();
+
+            TransferV1CpiBuilder::new(&token_metadata_program)
+                .token(&owner_token_account)
+                .token_owner(&owner)
+                .destination_token(&vault_token_account)
+                .destination_owner(&vault)
+                .mint(&mint)
+                .metadata(&metadata)
+                .edition(Some(edition))
+                .token_record(Some(owner_token_record))
+                .destination_token_record(Some(vault_token_record))
+                .authority(&owner)
+                .payer(&owner)
+                .system_program(&system_program)
+                .sysvar_instructions(sysvar_instructions)
+                .spl_token_program(&token_program)
+                .spl_ata_program(&associated_token_program)
+                .authorization_rules_program(self.authorization_rules_program.as_deref())
+                .authorization_rules(self.authorization_rules.as_deref())
+                .amount(1)
+                .invoke()?;
+        } else {
+            token::transfer(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.owner_token_account.to_account_info(),
+                        to: self.vault_token_account.to_account_info(),
+                        authority: self.owner.to_account_info(),
+                    },
+                ),
+                1,
+            )?;
+        }
+
+        let record = &mut self.nft_record;
+        record.vault = self.vault.key();
+        record.mint = self.mint.key();
+        record.is_programmable = is_programmable;
+        record.bump = record_bump;
+
+        let vault = &mut self.vault;
+        vault.locked_nfts = vault.locked_nfts.checked_add(1).ok_or(VaultError::Overflow)?;
+
+        msg!(
+            "Locked NFT {} in vault ({} locked)",
+            self.mint.key(),
+            vault.locked_nfts
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+//! Place a native stake account under the vault's authority.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_stake_interface::{instruction as stake_instruction, state::StakeAuthorize};
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct LockStakeAccount<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Current staker and withdrawer of the stake account
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Native stake account, deserialized and checked in the handler
+    #[account(mut, owner = solana_stake_interface::program::ID)]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// Record of this stake account in the vault (one per stake account)
+    #[account(
+        init,
+        payer = owner,
+        space = StakeRecord::SPACE,
+        seeds = [STAKE_SEED, vault.key().as_ref(), stake_account.key().as_ref()],
+        bump,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    /// Lockup custodian; must co-sign while the stake account's lockup is in force
+    pub custodian: Option<Signer<'info>>,
+
+    /// CHECK: Native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LockStakeAccount<'info> {
+    /// Handler for lock_stake_account instruction.
+    /// Moves both authorities to the vault PDA; the stake stays delegated and keeps earning.
+    pub fn handler(&mut self, record_bump: u8) -> Result<()> {
+        let meta = StakeRecord::stake_meta(&self.stake_account)?;
+        require!(
+            meta.authorized.staker == self.owner.key() && meta.authorized.withdrawer == self.owner.key(),
+            VaultError::InvalidStakeAccount
+        );
+
+        let custodian = self.custodian.as_ref().map(|custodian| custodian.to_account_info());
+        let custodian = StakeRecord::lockup_custodian(&meta, custodian.as_ref(), &self.clock)?;
+
+        let owner = self.owner.to_account_info();
+        authorize_stake_account(
+            &self.stake_account,
+            &owner,
+            &self.clock.to_account_info(),
+            &self.vault.key(),
+            custodian,
+            &[],
+        )?;
+
+        let record = &mut self.stake_record;
+        record.vault = self.vault.key();
+        record.stake_account = self.stake_account.key();
+        record.bump = record_bump;
+
+        let vault = &mut self.vault;
+        vault.locked_stake_accounts = vault
+            .locked_stake_accounts
+            .checked_add(1)
+            .ok_or(VaultError::Overflow)?;
+
+        msg!(
+            "Stake account {} placed under vault authority ({} locked)",
+            self.stake_account.key(),
+            vault.locked_stake_accounts
+        );
+
+        Ok(())
+    }
+}
+
+/// Hand both the staker and withdrawer authority of a stake account to `new_authority`.
+/// The withdrawer change carries the lockup custodian when one is required.
+pub(crate) fn authorize_stake_account<'info>(
+    stake_account: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    clock: &AccountInfo<'info>,
+    new_authority: &Pubkey,
+    custodian: Option<&AccountInfo<'info>>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_signed(
+        &stake_instruction::authorize(
+            stake_account.key,
+            authority.key,
+            new_authority,
+            StakeAuthorize::Staker,
+            None,
+        ),
+        &[stake_account.clone(), clock.clone(), authority.clone()],
+        signer_seeds,
+    )?;
+
+    let mut account_infos = vec![stake_account.clone(), clock.clone(), authority.clone()];
+    if let Some(custodian) = custodian {
+        account_infos.push(custodian.clone());
+    }
+    invoke_signed(
+        &stake_instruction::authorize(
+            stake_account.key,
+            authority.key,
+            new_authority,
+            StakeAuthorize::Withdrawer,
+            custodian.map(|custodian| custodian.key),
+        ),
+        &account_infos,
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! One-way commitment of the vault to its recipients.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct MakeIrrevocable<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+        constraint = !vault.has_legacy_plan @ VaultError::LegacyStepsPending,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> MakeIrrevocable<'info> {
+    /// Handler for make_irrevocable instruction.
+    /// Afterwards the vault can't be closed, emptied or redirected; pings and
+    /// longer intervals remain allowed. A stored legacy plan must be cleared first,
+    /// since its steps could still move the committed assets elsewhere.
+    pub fn handler(&mut self, co_signers: &[AccountInfo]) -> Result<()> {
+        let vault = &mut self.vault;
+
+        vault.require_revocable()?;
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::MakeIrrevocable,
+            &(),
+        )?;
+
+        vault.is_irrevocable = true;
+
+        msg!("Vault is now irrevocable. Recipient: {}", vault.recipient);
+
+        Ok(())
+    }
+}
@@ -11,6 +11,64 @@ pub mod claim_and_close;
 pub mod claim_sol;
 pub mod lock_tokens;
 pub mod claim_tokens;
+pub mod initialize_estate;
+pub mod ping_estate;
+pub mod link_estate;
+pub mod unlink_estate;
+pub mod set_co_owners;
+pub mod set_owner_authorities;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod close_proposal;
+pub mod set_recovery_guardians;
+pub mod propose_recovery;
+pub mod approve_recovery;
+pub mod cancel_recovery;
+pub mod recover_owner;
+pub mod snooze;
+pub mod set_release_mode;
+pub mod set_claim_restrictions;
+pub mod guardian_claim_sol;
+pub mod guardian_claim_tokens;
+pub mod set_fallback_recipients;
+pub mod pass_claim_rights;
+pub mod set_escalation_tiers;
+pub mod claim_tier_sol;
+pub mod claim_tier_tokens;
+pub mod cascade_claim_sol;
+pub mod cascade_claim_tokens;
+pub mod succeed;
+pub mod release_now;
+pub mod make_irrevocable;
+pub mod set_release_action;
+pub mod ping_with_signature;
+pub mod commit_hash_chain;
+pub mod set_liveness_adapters;
+pub mod ping_via_adapter;
+pub mod set_expiry_warning;
+pub mod announce_expiring;
+pub mod set_release_notice;
+pub mod lock_nft;
+pub mod claim_nft;
+pub mod lock_stake_account;
+pub mod claim_stake_account;
+pub mod reclaim_stake_account;
+pub mod stake_locked_sol;
+pub mod stake_locked_sol_in_pool;
+pub mod deposit_mint_authority;
+pub mod deposit_upgrade_authority;
+pub mod claim_authority;
+pub mod reclaim_authority;
+pub mod set_legacy_instructions;
+pub mod execute_legacy_step;
+pub mod set_allocations;
+pub mod skip_legacy_step;
+pub mod reclaim_nft;
+pub mod cascade_claim_nft;
+pub mod cascade_claim_stake_account;
+pub mod cascade_claim_authority;
+pub mod settle_auto_stake;
+pub mod unstake_locked_sol;
 
 pub use initialize_vault::*;
 pub use ping::*;
@@ -23,3 +81,61 @@ pub use claim_and_close::*;
 pub use claim_sol::*;
 pub use lock_tokens::*;
 pub use claim_tokens::*;
+pub use initialize_estate::*;
+pub use ping_estate::*;
+pub use link_estate::*;
+pub use unlink_estate::*;
+pub use set_co_owners::*;
+pub use set_owner_authorities::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use close_proposal::*;
+pub use set_recovery_guardians::*;
+pub use propose_recovery::*;
+pub use approve_recovery::*;
+pub use cancel_recovery::*;
+pub use recover_owner::*;
+pub use snooze::*;
+pub use set_release_mode::*;
+pub use set_claim_restrictions::*;
+pub use guardian_claim_sol::*;
+pub use guardian_claim_tokens::*;
+pub use set_fallback_recipients::*;
+pub use pass_claim_rights::*;
+pub use set_escalation_tiers::*;
+pub use claim_tier_sol::*;
+pub use claim_tier_tokens::*;
+pub use cascade_claim_sol::*;
+pub use cascade_claim_tokens::*;
+pub use succeed::*;
+pub use release_now::*;
+pub use make_irrevocable::*;
+pub use set_release_action::*;
+pub use ping_with_signature::*;
+pub use commit_hash_chain::*;
+pub use set_liveness_adapters::*;
+pub use ping_via_adapter::*;
+pub use set_expiry_warning::*;
+pub use announce_expiring::*;
+pub use set_release_notice::*;
+pub use lock_nft::*;
+pub use claim_nft::*;
+pub use lock_stake_account::*;
+pub use claim_stake_account::*;
+pub use reclaim_stake_account::*;
+pub use stake_locked_sol::*;
+pub use stake_locked_sol_in_pool::*;
+pub use deposit_mint_authority::*;
+pub use deposit_upgrade_authority::*;
+pub use claim_authority::*;
+pub use reclaim_authority::*;
+pub use set_legacy_instructions::*;
+pub use execute_legacy_step::*;
+pub use set_allocations::*;
+pub use skip_legacy_step::*;
+pub use reclaim_nft::*;
+pub use cascade_claim_nft::*;
+pub use cascade_claim_stake_account::*;
+pub use cascade_claim_authority::*;
+pub use settle_auto_stake::*;
+pub use unstake_locked_sol::*;
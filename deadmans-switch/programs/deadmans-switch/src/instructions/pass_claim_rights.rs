@@ -0,0 +1,90 @@
+//! Pass unclaimed assets to the next fallback recipient once the claim deadline lapses.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct PassClaimRights<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Anyone may trigger the handoff
+    pub caller: Signer<'info>,
+
+    /// Allocation table (required only for vaults routing assets by share); unclaimed shares pass too
+    #[account(
+        mut,
+        seeds = [ALLOCATION_SEED, vault.key().as_ref()],
+        bump = allocation_table.bump,
+    )]
+    pub allocation_table: Option<Account<'info, AllocationTable>>,
+}
+
+impl<'info> PassClaimRights<'info> {
+    /// Handler for pass_claim_rights instruction.
+    /// The new recipient starts a fresh claim window without the previous
+    /// recipient's claim-not-before date or minor guardian. Allocation shares
+    /// still unclaimed at the deadline pass to the same fallback recipient.
+    pub fn handler(&mut self) -> Result<()> {
+        let vault = &mut self.vault;
+        let clock = Clock::get()?;
+
+        require!(
+            vault.locked_lamports > 0
+                || vault.locked_tokens > 0
+                || vault.locked_nfts > 0
+                || vault.locked_stake_accounts > 0
+                || vault.custodied_authorities > 0
+                || vault.has_auto_stake,
+            VaultError::NothingToClaim
+        );
+        require!(vault.claim_deadline > 0, VaultError::NoFallbackRecipient);
+
+        let next = vault
+            .fallback_recipients
+            .get(vault.custody_log.len())
+            .copied()
+            .ok_or(VaultError::NoFallbackRecipient)?;
+
+        require!(
+            clock.unix_timestamp > vault.claim_window_end()?,
+            VaultError::ClaimDeadlineNotReached
+        );
+
+        if vault.has_allocation_table {
+            let table = self
+                .allocation_table
+                .as_mut()
+                .ok_or(VaultError::AllocationTableRequired)?;
+            for asset in table.assets.iter_mut() {
+                asset.hand_off(next);
+            }
+        }
+
+        let previous = vault.recipient;
+        vault.custody_log.push(CustodyHandoff {
+            from: previous,
+            to: next,
+            at: clock.unix_timestamp,
+        });
+        vault.recipient = next;
+        vault.claim_not_before = 0;
+        vault.minor_guardian = None;
+        vault.guardian_destination = None;
+
+        emit!(ClaimRightsPassed {
+            vault: vault.key(),
+            from: previous,
+            to: next,
+            handoff_index: (vault.custody_log.len() - 1) as u8,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Claim rights passed from {} to {}", previous, next);
+
+        Ok(())
+    }
+}
@@ -3,30 +3,57 @@
 use anchor_lang::prelude::*;
 use crate::{errors::*, state::*};
 
-/// Ping accounts - signer can be owner OR delegate.
+/// Ping accounts - signer can be owner, co-owner OR delegate.
 #[derive(Accounts)]
 pub struct Ping<'info> {
     #[account(mut)]
     pub vault: Account<'info, Vault>,
 
-    /// The signer - can be owner or delegate (validated in handler)
+    /// The signer - can be owner, co-owner or delegate (validated in handler)
     pub signer: Signer<'info>,
 }
 
 impl<'info> Ping<'info> {
     /// Handler for ping instruction.
     pub fn handler(&mut self) -> Result<()> {
+        self.vault.require_no_hash_chain()?;
+        self.check_in()
+    }
+
+    /// Handler for ping_with_preimage instruction.
+    /// Reveals the next hash-chain preimage, so a leaked key alone can't extend the vault.
+    pub fn handler_with_preimage(&mut self, preimage: [u8; 32]) -> Result<()> {
+        self.vault.reveal_preimage(&preimage)?;
+        self.check_in()
+    }
+
+    /// Reset the signer's heartbeat.
+    fn check_in(&mut self) -> Result<()> {
         let vault = &mut self.vault;
         let clock = Clock::get()?;
 
-        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_pingable()?;
 
-        // Authorization check - allow owner OR delegate
+        // Authorization check - allow owner, co-owner OR delegate
         let signer = self.signer.key();
         let is_owner = signer == vault.owner;
         let is_delegate = vault.delegate.map_or(false, |d| d == signer);
+        let co_owner = vault.co_owners.iter().position(|c| c.key == signer);
         
-        require!(is_owner || is_delegate, VaultError::Unauthorized);
+        require!(is_owner || is_delegate || co_owner.is_some(), VaultError::Unauthorized);
+
+        // A regular ping ends any snooze and restores the normal interval
+        vault.clear_snooze();
+
+        // Co-owners keep their own heartbeat; the delegate pings for the owner
+        if let Some(i) = co_owner {
+            vault.co_owners[i].last_check_in = clock.unix_timestamp;
+            msg!("Ping successful by co-owner. Timer reset to: {}", clock.unix_timestamp);
+            return Ok(());
+        }
+
+        // A linked vault takes the owner's heartbeat from its estate
+        vault.require_own_heartbeat()?;
 
         vault.last_check_in = clock.unix_timestamp;
 
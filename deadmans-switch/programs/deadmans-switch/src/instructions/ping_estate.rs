@@ -0,0 +1,35 @@
+//! Ping (check-in) an estate, resetting the timer of every linked vault.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct PingEstate<'info> {
+    #[account(
+        mut,
+        seeds = [ESTATE_SEED, owner.key().as_ref()],
+        bump = estate.bump,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub estate: Account<'info, Estate>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> PingEstate<'info> {
+    /// Handler for ping_estate instruction.
+    pub fn handler(&mut self) -> Result<()> {
+        let estate = &mut self.estate;
+        let clock = Clock::get()?;
+
+        estate.last_check_in = clock.unix_timestamp;
+
+        msg!(
+            "Estate ping successful. Timer reset to: {} for {} linked vault(s)",
+            estate.last_check_in,
+            estate.linked_vaults
+        );
+
+        Ok(())
+    }
+}
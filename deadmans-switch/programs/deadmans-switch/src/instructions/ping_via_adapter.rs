@@ -0,0 +1,67 @@
+//! Check-in on the owner's behalf by a registered liveness adapter program.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct PingViaAdapter<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// Owner or delegate, whose signature the adapter forwards through the CPI
+    pub signer: Signer<'info>,
+
+    /// CHECK: Instructions sysvar, used to identify the calling adapter program
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+impl<'info> PingViaAdapter<'info> {
+    /// Handler for ping_via_adapter instruction.
+    /// Must be reached through CPI; the adapter is the program of the enclosing
+    /// top-level instruction, and each adapter is rate limited separately.
+    /// The owner or delegate must co-sign, so an adapter cannot ping on its own.
+    pub fn handler(&mut self) -> Result<()> {
+        let vault = &mut self.vault;
+        let clock = Clock::get()?;
+
+        vault.require_pingable()?;
+        vault.require_no_hash_chain()?;
+        vault.require_own_heartbeat()?;
+
+        let signer = self.signer.key();
+        let is_delegate = vault.delegate == Some(signer);
+        require!(signer == vault.owner || is_delegate, VaultError::Unauthorized);
+
+        require!(
+            get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT,
+            VaultError::NotLivenessAdapter
+        );
+
+        let instructions = self.instructions.to_account_info();
+        let current_index = load_current_index_checked(&instructions)?;
+        let caller = load_instruction_at_checked(current_index as usize, &instructions)?.program_id;
+
+        let adapter = vault
+            .liveness_adapters
+            .iter_mut()
+            .find(|a| a.program_id == caller)
+            .ok_or(VaultError::NotLivenessAdapter)?;
+
+        let next_allowed = adapter
+            .last_ping
+            .checked_add(adapter.min_interval)
+            .ok_or(VaultError::Overflow)?;
+        require!(clock.unix_timestamp >= next_allowed, VaultError::AdapterRateLimited);
+        adapter.last_ping = clock.unix_timestamp;
+
+        vault.clear_snooze();
+        vault.last_check_in = clock.unix_timestamp;
+
+        msg!("Ping via adapter {} by {}. Timer reset to: {}", caller, signer, vault.last_check_in);
+
+        Ok(())
+    }
+}
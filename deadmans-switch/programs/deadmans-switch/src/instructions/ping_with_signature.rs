@@ -0,0 +1,94 @@
+//! Relayed check-in authorized by an ed25519 signature from the owner.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, instruction::Instruction};
+use solana_instructions_sysvar::get_instruction_relative;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct PingWithSignature<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// Anyone may relay the check-in and pay the fee
+    pub relayer: Signer<'info>,
+
+    /// CHECK: Instructions sysvar, used to find the ed25519 verification
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+impl<'info> PingWithSignature<'info> {
+    /// Handler for ping_with_signature instruction.
+    /// The instruction immediately before this one must be an Ed25519 sigverify
+    /// of the owner's signature over (program id, vault, nonce, expiry).
+    pub fn handler(&mut self, nonce: u64, expiry: i64) -> Result<()> {
+        let vault = &mut self.vault;
+        let clock = Clock::get()?;
+
+        vault.require_pingable()?;
+        vault.require_no_hash_chain()?;
+        vault.require_own_heartbeat()?;
+        require!(clock.unix_timestamp <= expiry, VaultError::CheckInExpired);
+        require!(nonce == vault.ping_nonce, VaultError::InvalidPingNonce);
+
+        let mut message = Vec::with_capacity(80);
+        message.extend_from_slice(crate::ID.as_ref());
+        message.extend_from_slice(vault.key().as_ref());
+        message.extend_from_slice(&nonce.to_le_bytes());
+        message.extend_from_slice(&expiry.to_le_bytes());
+
+        let sigverify = get_instruction_relative(-1, &self.instructions.to_account_info())
+            .map_err(|_| VaultError::InvalidCheckInSignature)?;
+        verify_ed25519_instruction(&sigverify, &vault.owner, &message)?;
+
+        vault.ping_nonce = nonce.checked_add(1).ok_or(VaultError::Overflow)?;
+        vault.clear_snooze();
+        vault.last_check_in = clock.unix_timestamp;
+
+        msg!(
+            "Signed ping relayed by {}. Timer reset to: {}",
+            self.relayer.key(),
+            vault.last_check_in
+        );
+
+        Ok(())
+    }
+}
+
+/// Check that an Ed25519 program instruction verified exactly one signature by
+/// `signer` over `message`, with all offsets pointing into its own data.
+fn verify_ed25519_instruction(ix: &Instruction, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    require_keys_eq!(ix.program_id, ed25519_program::ID, VaultError::InvalidCheckInSignature);
+
+    let data = &ix.data;
+    let read_u16 = |at: usize| -> Result<u16> {
+        data.get(at..at + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .ok_or_else(|| VaultError::InvalidCheckInSignature.into())
+    };
+
+    // Header: signature count and padding, then one 14-byte offsets record
+    require!(data.first() == Some(&1), VaultError::InvalidCheckInSignature);
+    let signature_ix = read_u16(4)?;
+    let public_key_offset = read_u16(6)? as usize;
+    let public_key_ix = read_u16(8)?;
+    let message_offset = read_u16(10)? as usize;
+    let message_size = read_u16(12)? as usize;
+    let message_ix = read_u16(14)?;
+
+    require!(
+        signature_ix == u16::MAX && public_key_ix == u16::MAX && message_ix == u16::MAX,
+        VaultError::InvalidCheckInSignature
+    );
+    require!(
+        data.get(public_key_offset..public_key_offset + 32) == Some(signer.as_ref()),
+        VaultError::InvalidCheckInSignature
+    );
+    require!(
+        data.get(message_offset..message_offset + message_size) == Some(message),
+        VaultError::InvalidCheckInSignature
+    );
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! Propose rotating a lost owner key to a new one.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct ProposeRecovery<'info> {
+    #[account(
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        constraint = recovery.owner == vault.owner @ VaultError::StaleRecovery,
+        constraint = recovery.guardians.contains(&guardian.key()) @ VaultError::NotGuardian,
+        constraint = recovery.pending.is_none() @ VaultError::RecoveryPending,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    pub guardian: Signer<'info>,
+}
+
+impl<'info> ProposeRecovery<'info> {
+    /// Handler for propose_recovery instruction.
+    /// The proposing guardian's approval is recorded immediately.
+    pub fn handler(&mut self, new_owner: Pubkey) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(
+            !self.vault.is_admin_key(&new_owner),
+            VaultError::DuplicateCoOwner
+        );
+
+        self.recovery.pending = Some(PendingRecovery {
+            new_owner,
+            approvals: vec![self.guardian.key()],
+            initiated_at: clock.unix_timestamp,
+        });
+
+        msg!("Recovery proposed by guardian {}", self.guardian.key());
+        msg!("New owner: {}", new_owner);
+
+        Ok(())
+    }
+}
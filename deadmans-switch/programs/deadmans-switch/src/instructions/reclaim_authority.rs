@@ -0,0 +1,88 @@
+//! Take a custodied authority back from an active vault.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use solana_sdk_ids::bpf_loader_upgradeable;
+use crate::{constants::*, errors::*, state::*};
+use super::claim_authority::hand_over_authority;
+
+#[derive(Accounts)]
+pub struct ReclaimAuthority<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Record of the authority being reclaimed; closed to the owner
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            AUTHORITY_SEED,
+            vault.key().as_ref(),
+            authority_record.target.as_ref(),
+            &[authority_record.kind as u8],
+        ],
+        bump = authority_record.bump,
+    )]
+    pub authority_record: Account<'info, AuthorityRecord>,
+
+    /// The mint (mint and freeze authorities only)
+    #[account(mut)]
+    pub mint: Option<Account<'info, Mint>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// The program's data account (upgrade authorities only)
+    #[account(mut)]
+    pub program_data: Option<Account<'info, ProgramData>>,
+
+    /// CHECK: BPF upgradeable loader (upgrade authorities only); the set-authority instruction targets it by ID
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader: Option<UncheckedAccount<'info>>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> ReclaimAuthority<'info> {
+    /// Handler for reclaim_authority instruction.
+    /// Returns the authority to the owner. Not available on irrevocable vaults.
+    pub fn handler(&mut self, co_signers: &[AccountInfo]) -> Result<()> {
+        self.vault.require_revocable()?;
+        self.vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::ReclaimAuthority,
+            &(self.authority_record.target, self.authority_record.kind),
+        )?;
+
+        hand_over_authority(
+            &self.vault,
+            &self.authority_record,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            self.program_data.as_ref(),
+            self.bpf_loader.as_ref(),
+            &self.owner.to_account_info(),
+        )?;
+
+        let vault = &mut self.vault;
+        vault.custodied_authorities -= 1;
+
+        msg!(
+            "{:?} authority over {} returned to owner",
+            self.authority_record.kind,
+            self.authority_record.target
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+//! Take a locked NFT back from an active vault.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::Metadata,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{constants::*, errors::*, state::*};
+use super::claim_nft::{transfer_nft_out, NftTransferOut};
+
+#[derive(Accounts)]
+pub struct ReclaimNft<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(address = nft_record.mint @ VaultError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Record of the NFT being reclaimed; closed to the owner
+    #[account(
+        mut,
+        close = owner,
+        seeds = [NFT_SEED, vault.key().as_ref(), mint.key().as_ref()],
+        bump = nft_record.bump,
+    )]
+    pub nft_record: Account<'info, NftRecord>,
+
+    /// Vault's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Owner's token account (destination)
+    /// Will be created if doesn't exist
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Token Metadata account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Master edition (programmable NFTs only), verified by Token Metadata
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token record of the vault's token account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub vault_token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token record of the owner's token account (programmable NFTs only), verified by Token Metadata
+    #[account(mut)]
+    pub owner_token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rule set named by the NFT's programmable config, verified by Token Metadata
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Auth Rules program (only with a rule set), verified by Token Metadata
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+
+    /// CHECK: Instructions sysvar, required by Token Metadata for programmable transfers
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReclaimNft<'info> {
+    /// Handler for reclaim_nft instruction.
+    /// Returns the NFT to the owner. Not available on irrevocable vaults.
+    pub fn handler(&mut self, co_signers: &[AccountInfo]) -> Result<()> {
+        self.vault.require_revocable()?;
+        self.vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::ReclaimNft,
+            &self.mint.key(),
+        )?;
+
+        transfer_nft_out(NftTransferOut {
+            vault: &self.vault,
+            record: &self.nft_record,
+            mint: &self.mint,
+            vault_token_account: &mut self.vault_token_account,
+            destination_token_account: self.owner_token_account.to_account_info(),
+            destination_owner: self.owner.to_account_info(),
+            payer: self.owner.to_account_info(),
+            metadata: self.metadata.as_ref(),
+            edition: self.edition.as_ref(),
+            vault_token_record: self.vault_token_record.as_ref(),
+            destination_token_record: self.owner_token_record.as_ref(),
+            authorization_rules: self.authorization_rules.as_ref(),
+            authorization_rules_program: self.authorization_rules_program.as_ref(),
+            token_metadata_program: self.token_metadata_program.as_ref(),
+            sysvar_instructions: self.sysvar_instructions.as_ref(),
+            token_program: &self.token_program,
+            associated_token_program: &self.associated_token_program,
+            system_program: &self.system_program,
+        })?;
+
+        let vault = &mut self.vault;
+        vault.locked_nfts -= 1;
+
+        msg!(
+            "NFT {} returned to owner ({} still locked)",
+            self.mint.key(),
+            vault.locked_nfts
+        );
+
+        Ok(())
+    }
+}
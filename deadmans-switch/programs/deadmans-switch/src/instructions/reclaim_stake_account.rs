@@ -0,0 +1,92 @@
+//! Take a stake account back from an active vault.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+use super::lock_stake_account::authorize_stake_account;
+
+#[derive(Accounts)]
+pub struct ReclaimStakeAccount<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Native stake account named by the stake record
+    #[account(mut, address = stake_record.stake_account @ VaultError::InvalidStakeAccount)]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// Record of the stake account being reclaimed; closed to the owner
+    #[account(
+        mut,
+        close = owner,
+        seeds = [STAKE_SEED, vault.key().as_ref(), stake_account.key().as_ref()],
+        bump = stake_record.bump,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    /// Lockup custodian; must co-sign while the stake account's lockup is in force
+    pub custodian: Option<Signer<'info>>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    /// CHECK: Native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ReclaimStakeAccount<'info> {
+    /// Handler for reclaim_stake_account instruction.
+    /// Returns both authorities to the owner. Not available on irrevocable vaults.
+    pub fn handler(&mut self, co_signers: &[AccountInfo]) -> Result<()> {
+        self.vault.require_revocable()?;
+        self.vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::ReclaimStakeAccount,
+            &self.stake_account.key(),
+        )?;
+
+        let meta = StakeRecord::stake_meta(&self.stake_account)?;
+        let custodian = self.custodian.as_ref().map(|custodian| custodian.to_account_info());
+        let custodian = StakeRecord::lockup_custodian(&meta, custodian.as_ref(), &self.clock)?;
+
+        // PDA seeds for signing
+        let creator_key = self.vault.creator;
+        let vault_seed = self.vault.vault_seed;
+        let bump = self.vault.bump;
+
+        let seeds = &[
+            VAULT_SEED,
+            creator_key.as_ref(),
+            &vault_seed.to_le_bytes(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        authorize_stake_account(
+            &self.stake_account,
+            &self.vault.to_account_info(),
+            &self.clock.to_account_info(),
+            &self.owner.key(),
+            custodian,
+            signer_seeds,
+        )?;
+
+        let vault = &mut self.vault;
+        vault.locked_stake_accounts -= 1;
+
+        msg!("Stake account {} returned to owner", self.stake_account.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! Rotate the controlling owner key once guardians approve and the timelock elapses.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct RecoverOwner<'info> {
+    #[account(
+        mut,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [RECOVERY_SEED, vault.key().as_ref()],
+        bump = recovery.bump,
+        constraint = recovery.owner == vault.owner @ VaultError::StaleRecovery,
+        constraint = recovery.guardians.contains(&guardian.key()) @ VaultError::NotGuardian,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    pub guardian: Signer<'info>,
+
+    /// Estate the vault is linked to; the lost key controls it, so the vault is unlinked
+    #[account(mut)]
+    pub estate: Option<Account<'info, Estate>>,
+}
+
+impl<'info> RecoverOwner<'info> {
+    /// Handler for recover_owner instruction.
+    /// Rotates the owner, resets the timer and clears the delegate.
+    pub fn handler(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        let recovery = &mut self.recovery;
+        let pending = recovery.pending.take().ok_or(VaultError::NoPendingRecovery)?;
+
+        let approvals = pending
+            .approvals
+            .iter()
+            .filter(|a| recovery.guardians.contains(a))
+            .count();
+        require!(
+            approvals >= recovery.threshold as usize,
+            VaultError::InsufficientSignatures
+        );
+
+        let unlock_time = pending
+            .initiated_at
+            .checked_add(recovery.timelock)
+            .ok_or(VaultError::Overflow)?;
+        require!(clock.unix_timestamp >= unlock_time, VaultError::RecoveryTimelocked);
+
+        let vault = &mut self.vault;
+        if vault.linked_estate(self.estate.as_ref())?.is_some() {
+            if let Some(estate) = self.estate.as_mut() {
+                estate.linked_vaults = estate.linked_vaults.saturating_sub(1);
+            }
+            vault.estate = None;
+        }
+
+        let previous_owner = vault.owner;
+        vault.owner = pending.new_owner;
+        vault.last_check_in = clock.unix_timestamp;
+
+        // Check-in credentials set up by the lost key go with it
+        vault.delegate = None;
+        vault.hash_chain_head = None;
+        vault.hash_chain_remaining = 0;
+        vault.liveness_adapters.clear();
+        recovery.owner = pending.new_owner;
+
+        msg!("Owner recovered: {} -> {}", previous_owner, vault.owner);
+        msg!("Timer reset to: {}", vault.last_check_in);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+//! Owner-initiated immediate release of the vault.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct ReleaseNow<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::ReleaseActionConflict,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> ReleaseNow<'info> {
+    /// Handler for release_now instruction.
+    /// Releases the vault without waiting for the deadline. With `refund_bounty` the
+    /// unused bounty returns to the owner; otherwise it joins the recipient's locked SOL.
+    pub fn handler(&mut self, co_signers: &[AccountInfo], refund_bounty: bool) -> Result<()> {
+        let vault = &mut self.vault;
+        let clock = Clock::get()?;
+
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::ReleaseNow,
+            &refund_bounty,
+        )?;
+
+        // No hunter is paid; the bounty goes back to the owner or on to the recipient
+        let bounty = vault.bounty_lamports;
+        let bounty_to_recipient = if refund_bounty { 0 } else { bounty };
+        if bounty > 0 {
+            if refund_bounty {
+                **vault.to_account_info().try_borrow_mut_lamports()? -= bounty;
+                **self.owner.to_account_info().try_borrow_mut_lamports()? += bounty;
+                msg!("Bounty of {} lamports refunded to owner", bounty);
+            } else {
+                vault.locked_lamports = vault
+                    .locked_lamports
+                    .checked_add(bounty)
+                    .ok_or(VaultError::Overflow)?;
+                msg!("Bounty of {} lamports added to the recipient's locked SOL", bounty);
+            }
+            vault.bounty_lamports = 0;
+        }
+
+        vault.mark_released(clock.unix_timestamp);
+
+        emit!(VaultReleased {
+            vault: vault.key(),
+            recipient: vault.recipient,
+            released_by: self.owner.key(),
+            bounty_lamports: 0,
+            bounty_to_recipient,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Vault released by owner! Recipient {} can now claim.", vault.recipient);
+
+        Ok(())
+    }
+}
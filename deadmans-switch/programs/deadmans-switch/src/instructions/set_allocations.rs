@@ -0,0 +1,72 @@
+//! Route locked assets to their own recipients through a per-asset allocation table.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetAllocations<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = AllocationTable::SPACE,
+        seeds = [ALLOCATION_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub allocation_table: Account<'info, AllocationTable>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetAllocations<'info> {
+    /// Handler for set_allocations instruction.
+    /// Replaces the whole table; an empty list routes every asset back to the recipient.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        assets: Vec<AssetAllocation>,
+        bump: u8,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_revocable()?;
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetAllocations,
+            &assets,
+        )?;
+
+        require!(
+            vault.release_action == ReleaseAction::Release,
+            VaultError::ReleaseActionConflict
+        );
+
+        AllocationTable::validate(&assets)?;
+
+        vault.has_allocation_table = !assets.is_empty();
+        self.allocation_table.set_inner(AllocationTable {
+            vault: vault.key(),
+            assets,
+            bump,
+        });
+
+        msg!("Allocation table updated: {} asset(s)", self.allocation_table.assets.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+//! Set the recipient's claim-not-before date and an optional guardian for a minor.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetClaimRestrictions<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> SetClaimRestrictions<'info> {
+    /// Handler for set_claim_restrictions instruction.
+    /// A guardian always comes with the restricted destination it may claim into.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        claim_not_before: i64,
+        minor_guardian: Option<Pubkey>,
+        guardian_destination: Option<Pubkey>,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_revocable()?;
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetClaimRestrictions,
+            &(claim_not_before, &minor_guardian, &guardian_destination),
+        )?;
+
+        require!(claim_not_before >= 0, VaultError::InvalidReleaseDate);
+        require!(
+            minor_guardian.is_some() == guardian_destination.is_some(),
+            VaultError::InvalidDestination
+        );
+
+        vault.claim_not_before = claim_not_before;
+        vault.minor_guardian = minor_guardian;
+        vault.guardian_destination = guardian_destination;
+
+        msg!("Claim not before: {}", claim_not_before);
+        match minor_guardian {
+            Some(guardian) => msg!("Minor guardian set to: {}", guardian),
+            None => msg!("Minor guardian cleared"),
+        }
+
+        Ok(())
+    }
+}
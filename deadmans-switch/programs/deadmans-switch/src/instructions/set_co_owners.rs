@@ -0,0 +1,76 @@
+//! Configure the co-owners of a joint vault and how their inactivity combines.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetCoOwners<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> SetCoOwners<'info> {
+    /// Handler for set_co_owners instruction.
+    /// Existing co-owners keep their check-in; newly added ones start now.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        co_owners: Vec<Pubkey>,
+        survivor_policy: SurvivorPolicy,
+        admin_threshold: u8,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+        let clock = Clock::get()?;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetCoOwners,
+            &(&co_owners, survivor_policy, admin_threshold),
+        )?;
+
+        require!(co_owners.len() <= MAX_CO_OWNERS, VaultError::TooManyCoOwners);
+        for (i, key) in co_owners.iter().enumerate() {
+            require!(
+                *key != vault.owner
+                    && !vault.authorities.contains(key)
+                    && !co_owners[..i].contains(key),
+                VaultError::DuplicateCoOwner
+            );
+        }
+        vault.co_owners = co_owners
+            .iter()
+            .map(|key| CoOwner {
+                key: *key,
+                last_check_in: vault
+                    .co_owners
+                    .iter()
+                    .find(|c| c.key == *key)
+                    .map_or(clock.unix_timestamp, |c| c.last_check_in),
+            })
+            .collect();
+        vault.survivor_policy = survivor_policy;
+        require!(
+            admin_threshold >= 1 && admin_threshold as usize <= vault.admin_key_count(),
+            VaultError::InvalidThreshold
+        );
+        vault.admin_threshold = admin_threshold;
+
+        msg!("Co-owners updated: {} co-owner(s)", vault.co_owners.len());
+        msg!("Survivor policy: {:?}", survivor_policy);
+        msg!("Admin threshold: {} signature(s)", admin_threshold);
+
+        Ok(())
+    }
+}
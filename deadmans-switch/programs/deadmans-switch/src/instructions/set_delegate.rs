@@ -12,14 +12,25 @@ pub struct SetDelegate<'info> {
     pub vault: Account<'info, Vault>,
 
     pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
 }
 
 impl<'info> SetDelegate<'info> {
     /// Handler for set_delegate instruction.
-    pub fn handler(&mut self, new_delegate: Option<Pubkey>) -> Result<()> {
+    pub fn handler(&mut self, co_signers: &[AccountInfo], new_delegate: Option<Pubkey>) -> Result<()> {
         let vault = &mut self.vault;
 
         require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetDelegate,
+            &new_delegate,
+        )?;
 
         vault.delegate = new_delegate;
 
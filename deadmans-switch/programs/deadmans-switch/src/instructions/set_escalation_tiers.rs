@@ -0,0 +1,131 @@
+//! Configure staged inheritance tiers released at increasing inactivity depths.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetEscalationTiers<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = EscalationPlan::SPACE,
+        seeds = [ESCALATION_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub escalation_plan: Account<'info, EscalationPlan>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetEscalationTiers<'info> {
+    /// Handler for set_escalation_tiers instruction.
+    /// Tier asset slices are carved out of the vault's locked SOL and tokens;
+    /// replacing the tiers returns the previous slices first. Tier bounties are
+    /// topped up from, or refunded to, the owner.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        tiers: Vec<TierConfig>,
+        bump: u8,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+        let plan = &mut self.escalation_plan;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_revocable()?;
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetEscalationTiers,
+            &tiers,
+        )?;
+
+        require!(
+            vault.release_mode != ReleaseMode::FixedDate,
+            VaultError::ReleaseModeMismatch
+        );
+        require!(
+            vault.release_action == ReleaseAction::Release,
+            VaultError::ReleaseActionConflict
+        );
+        require!(plan.next_tier == 0, VaultError::EscalationStarted);
+        require!(tiers.len() <= MAX_ESCALATION_TIERS, VaultError::TooManyTiers);
+
+        let mut previous_inactivity = 0;
+        for tier in &tiers {
+            require!(tier.inactivity > previous_inactivity, VaultError::InvalidTierOrder);
+            require!(tier.claim_not_before >= 0, VaultError::InvalidReleaseDate);
+            require!(
+                tier.encrypted_key.len() <= MAX_ENCRYPTED_KEY_LEN,
+                VaultError::EncryptedKeyTooLong
+            );
+            previous_inactivity = tier.inactivity;
+        }
+
+        let tiers: Vec<EscalationTier> = tiers.into_iter().map(Into::into).collect();
+        let (old_lamports, old_tokens, old_bounty) = plan.reserved()?;
+        let new_plan = EscalationPlan {
+            vault: vault.key(),
+            tiers,
+            next_tier: 0,
+            bump,
+        };
+        let (new_lamports, new_tokens, new_bounty) = new_plan.reserved()?;
+
+        // Return the previous slices, then carve out the new ones
+        vault.locked_lamports = vault
+            .locked_lamports
+            .checked_add(old_lamports)
+            .and_then(|l| l.checked_sub(new_lamports))
+            .ok_or(VaultError::InsufficientBalance)?;
+        vault.locked_tokens = vault
+            .locked_tokens
+            .checked_add(old_tokens)
+            .and_then(|t| t.checked_sub(new_tokens))
+            .ok_or(VaultError::InsufficientBalance)?;
+
+        if new_bounty > old_bounty {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.owner.to_account_info(),
+                        to: vault.to_account_info(),
+                    },
+                ),
+                new_bounty - old_bounty,
+            )?;
+        } else if old_bounty > new_bounty {
+            let refund = old_bounty - new_bounty;
+            **vault.to_account_info().try_borrow_mut_lamports()? -= refund;
+            **self.owner.to_account_info().try_borrow_mut_lamports()? += refund;
+        }
+
+        vault.has_escalation_plan = !new_plan.tiers.is_empty();
+        plan.set_inner(new_plan);
+
+        msg!("Escalation plan updated: {} tier(s)", plan.tiers.len());
+        msg!(
+            "Reserved: {} lamports, {} tokens, {} lamports in tier bounties",
+            new_lamports,
+            new_tokens,
+            new_bounty
+        );
+
+        Ok(())
+    }
+}
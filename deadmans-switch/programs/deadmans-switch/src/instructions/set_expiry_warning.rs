@@ -0,0 +1,76 @@
+//! Configure the expiry warning window and its notification bounty.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetExpiryWarning<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetExpiryWarning<'info> {
+    /// Handler for set_expiry_warning instruction.
+    /// `deposit` lamports are added to the notification pool from the owner.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        warning_window: i64,
+        notification_bounty: u64,
+        deposit: u64,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetExpiryWarning,
+            &(warning_window, notification_bounty, deposit),
+        )?;
+
+        require!(warning_window >= 0, VaultError::InvalidTimeInterval);
+
+        if deposit > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.owner.to_account_info(),
+                        to: vault.to_account_info(),
+                    },
+                ),
+                deposit,
+            )?;
+            vault.notification_pool = vault
+                .notification_pool
+                .checked_add(deposit)
+                .ok_or(VaultError::Overflow)?;
+        }
+
+        vault.warning_window = warning_window;
+        vault.notification_bounty = notification_bounty;
+
+        msg!(
+            "Expiry warning: {} seconds before deadline, {} lamports per announcement",
+            warning_window,
+            notification_bounty
+        );
+        msg!("Notification pool: {} lamports", vault.notification_pool);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+//! Configure fallback recipients and how long each recipient has to claim.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetFallbackRecipients<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> SetFallbackRecipients<'info> {
+    /// Handler for set_fallback_recipients instruction.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        fallback_recipients: Vec<Pubkey>,
+        claim_deadline: i64,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_revocable()?;
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetFallbackRecipients,
+            &(&fallback_recipients, claim_deadline),
+        )?;
+
+        require!(
+            fallback_recipients.len() <= MAX_FALLBACK_RECIPIENTS,
+            VaultError::TooManyFallbacks
+        );
+        require!(claim_deadline >= 0, VaultError::InvalidTimeInterval);
+
+        vault.fallback_recipients = fallback_recipients;
+        vault.claim_deadline = claim_deadline;
+
+        msg!(
+            "Fallback recipients updated: {} fallback(s), claim deadline {} seconds",
+            vault.fallback_recipients.len(),
+            claim_deadline
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+//! Store the instructions the vault signs after release.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetLegacyInstructions<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = LegacyPlan::SPACE,
+        seeds = [LEGACY_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub legacy_plan: Account<'info, LegacyPlan>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetLegacyInstructions<'info> {
+    /// Handler for set_legacy_instructions instruction.
+    /// Replaces the stored steps; an empty list clears the plan. The list is
+    /// locked once the vault is released.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        steps: Vec<LegacyStepConfig>,
+        bump: u8,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+
+        vault.require_revocable()?;
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetLegacyInstructions,
+            &steps,
+        )?;
+
+        require!(
+            vault.release_action == ReleaseAction::Release,
+            VaultError::ReleaseActionConflict
+        );
+
+        LegacyPlan::validate(&vault.key(), &steps)?;
+
+        vault.has_legacy_plan = !steps.is_empty();
+        self.legacy_plan.set_inner(LegacyPlan {
+            vault: vault.key(),
+            steps: steps.into_iter().map(Into::into).collect(),
+            next_step: 0,
+            bump,
+        });
+
+        msg!("Legacy plan updated: {} step(s)", self.legacy_plan.steps.len());
+
+        Ok(())
+    }
+}
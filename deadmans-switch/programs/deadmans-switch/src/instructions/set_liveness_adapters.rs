@@ -0,0 +1,52 @@
+//! Register programs whose CPIs count as the owner's check-in.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetLivenessAdapters<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> SetLivenessAdapters<'info> {
+    /// Handler for set_liveness_adapters instruction.
+    /// Replaces the adapter list; an empty list disables adapter check-ins.
+    pub fn handler(&mut self, co_signers: &[AccountInfo], adapters: Vec<AdapterConfig>) -> Result<()> {
+        let vault = &mut self.vault;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetLivenessAdapters,
+            &adapters,
+        )?;
+
+        require!(adapters.len() <= MAX_LIVENESS_ADAPTERS, VaultError::TooManyAdapters);
+        for (i, adapter) in adapters.iter().enumerate() {
+            require!(
+                adapter.program_id != crate::ID
+                    && adapter.min_interval >= 0
+                    && adapters[..i].iter().all(|a| a.program_id != adapter.program_id),
+                VaultError::InvalidAdapter
+            );
+        }
+
+        vault.liveness_adapters = adapters.into_iter().map(Into::into).collect();
+
+        msg!("Liveness adapters updated: {} adapter(s)", vault.liveness_adapters.len());
+
+        Ok(())
+    }
+}
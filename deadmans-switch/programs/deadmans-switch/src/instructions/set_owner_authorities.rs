@@ -0,0 +1,63 @@
+//! Configure the owner-authority set and the approval threshold for administrative actions.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetOwnerAuthorities<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> SetOwnerAuthorities<'info> {
+    /// Handler for set_owner_authorities instruction.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        authorities: Vec<Pubkey>,
+        admin_threshold: u8,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetOwnerAuthorities,
+            &(&authorities, admin_threshold),
+        )?;
+
+        require!(
+            authorities.len() <= MAX_OWNER_AUTHORITIES,
+            VaultError::TooManyAuthorities
+        );
+        for (i, key) in authorities.iter().enumerate() {
+            require!(
+                !vault.is_owner_key(key) && !authorities[..i].contains(key),
+                VaultError::DuplicateCoOwner
+            );
+        }
+
+        vault.authorities = authorities;
+        require!(
+            admin_threshold >= 1 && admin_threshold as usize <= vault.admin_key_count(),
+            VaultError::InvalidThreshold
+        );
+        vault.admin_threshold = admin_threshold;
+
+        msg!("Owner authorities updated: {} authority(ies)", vault.authorities.len());
+        msg!("Admin threshold: {} approval(s)", admin_threshold);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+//! Register the guardians that can recover a lost owner key.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetRecoveryGuardians<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = Recovery::SPACE,
+        seeds = [RECOVERY_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetRecoveryGuardians<'info> {
+    /// Handler for set_recovery_guardians instruction.
+    /// Replacing the guardians discards any recovery in progress.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        timelock: i64,
+        bump: u8,
+    ) -> Result<()> {
+        self.vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetRecoveryGuardians,
+            &(&guardians, threshold, timelock),
+        )?;
+
+        require!(guardians.len() <= MAX_GUARDIANS, VaultError::TooManyGuardians);
+        for (i, key) in guardians.iter().enumerate() {
+            require!(
+                *key != self.vault.owner && !guardians[..i].contains(key),
+                VaultError::DuplicateCoOwner
+            );
+        }
+        require!(
+            threshold >= 1 && threshold as usize <= guardians.len(),
+            VaultError::InvalidThreshold
+        );
+        require!(timelock >= 0, VaultError::InvalidTimeInterval);
+
+        let recovery = &mut self.recovery;
+        recovery.vault = self.vault.key();
+        recovery.owner = self.vault.owner;
+        recovery.guardians = guardians;
+        recovery.threshold = threshold;
+        recovery.timelock = timelock;
+        recovery.pending = None;
+        recovery.bump = bump;
+        self.vault.has_recovery = true;
+
+        msg!(
+            "Recovery guardians set: {}-of-{}, timelock {} seconds",
+            threshold,
+            recovery.guardians.len(),
+            timelock
+        );
+
+        Ok(())
+    }
+}
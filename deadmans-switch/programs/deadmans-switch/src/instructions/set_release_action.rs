@@ -0,0 +1,55 @@
+//! Choose whether expiry releases the vault or destroys its contents.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetReleaseAction<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> SetReleaseAction<'info> {
+    /// Handler for set_release_action instruction.
+    /// Destroy actions take the contents away from the recipient, so they are not
+    /// available to irrevocable vaults, vaults with staged escalation tiers, an
+    /// allocation table or a legacy plan, or vaults whose SOL is auto-staked.
+    pub fn handler(&mut self, co_signers: &[AccountInfo], release_action: ReleaseAction) -> Result<()> {
+        let vault = &mut self.vault;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_revocable()?;
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetReleaseAction,
+            &release_action,
+        )?;
+
+        if release_action != ReleaseAction::Release {
+            require!(
+                !vault.has_escalation_plan
+                    && !vault.has_auto_stake
+                    && !vault.has_allocation_table
+                    && !vault.has_legacy_plan,
+                VaultError::ReleaseActionConflict
+            );
+        }
+
+        vault.release_action = release_action;
+
+        msg!("Release action set to {:?}", release_action);
+
+        Ok(())
+    }
+}
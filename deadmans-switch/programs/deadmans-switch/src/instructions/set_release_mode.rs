@@ -0,0 +1,60 @@
+//! Switch a vault from heartbeat release to a fixed-date or hybrid release.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetReleaseMode<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> SetReleaseMode<'info> {
+    /// Handler for set_release_mode instruction.
+    /// The switch is one-way: afterwards the date can only be moved later via update_vault.
+    pub fn handler(
+        &mut self,
+        co_signers: &[AccountInfo],
+        release_mode: ReleaseMode,
+        release_at: i64,
+    ) -> Result<()> {
+        let vault = &mut self.vault;
+        let clock = Clock::get()?;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetReleaseMode,
+            &(release_mode, release_at),
+        )?;
+
+        require!(
+            vault.release_mode == ReleaseMode::Heartbeat && release_mode != ReleaseMode::Heartbeat,
+            VaultError::ReleaseModeMismatch
+        );
+        // Escalation tiers run on inactivity, which a time capsule no longer tracks
+        require!(
+            !vault.has_escalation_plan || release_mode != ReleaseMode::FixedDate,
+            VaultError::ReleaseModeMismatch
+        );
+        require!(release_at > clock.unix_timestamp, VaultError::InvalidReleaseDate);
+
+        vault.release_mode = release_mode;
+        vault.release_at = release_at;
+
+        msg!("Release mode set to {:?} at {}", release_mode, release_at);
+
+        Ok(())
+    }
+}
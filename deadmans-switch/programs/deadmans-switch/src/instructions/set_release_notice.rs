@@ -0,0 +1,46 @@
+//! Configure the on-chain notice sent to the recipient on release.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetReleaseNotice<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+impl<'info> SetReleaseNotice<'info> {
+    /// Handler for set_release_notice instruction.
+    /// The notice is funded from the bounty at release; zero lamports and an empty
+    /// memo turn it off.
+    pub fn handler(&mut self, co_signers: &[AccountInfo], lamports: u64, memo: String) -> Result<()> {
+        let vault = &mut self.vault;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::SetReleaseNotice,
+            &(lamports, &memo),
+        )?;
+
+        require!(memo.len() <= MAX_NOTICE_MEMO_LEN, VaultError::NoticeMemoTooLong);
+
+        vault.release_notice_lamports = lamports;
+        vault.release_notice_memo = memo;
+
+        msg!("Release notice set: {} lamports", lamports);
+
+        Ok(())
+    }
+}
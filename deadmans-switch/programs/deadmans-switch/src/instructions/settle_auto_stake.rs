@@ -0,0 +1,192 @@
+//! Settle a released vault's auto-staked SOL back into its locked assets.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_stake_interface::{instruction as stake_instruction, state::StakeStateV2};
+use crate::{constants::*, errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SettleAutoStake<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = vault.has_auto_stake @ VaultError::AutoStakeRequired,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Anyone may settle, e.g. a minor's guardian before the claim-not-before date
+    pub caller: Signer<'info>,
+
+    /// Auto-staked SOL to settle
+    #[account(
+        mut,
+        seeds = [AUTO_STAKE_SEED, vault.key().as_ref()],
+        bump = auto_stake.bump,
+    )]
+    pub auto_stake: Account<'info, AutoStake>,
+
+    /// CHECK: The auto-stake target (native stake account or stake pool), checked against the AutoStake record
+    #[account(mut)]
+    pub stake_target: UncheckedAccount<'info>,
+
+    /// CHECK: Stake history sysvar (native stake only), verified by the stake program
+    pub stake_history: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Native stake program (native stake only)
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: Option<UncheckedAccount<'info>>,
+
+    pub clock: Option<Sysvar<'info, Clock>>,
+}
+
+impl<'info> SettleAutoStake<'info> {
+    /// Handler for settle_auto_stake instruction.
+    /// Not subject to the claim-not-before date: settling only moves value between the
+    /// vault's own locked assets. The AutoStake record's rent joins the locked SOL.
+    pub fn handler(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        let rent = self.auto_stake.to_account_info().lamports();
+
+        let settled = settle_auto_stake(
+            AutoStakeSettlement {
+                vault: &mut self.vault,
+                auto_stake: Some(&self.auto_stake),
+                stake_target: Some(&self.stake_target),
+                stake_history: self.stake_history.as_ref(),
+                stake_program: self.stake_program.as_ref(),
+                clock: self.clock.as_ref(),
+                rent_destination: None,
+            },
+            &clock,
+        )?;
+
+        if settled {
+            let vault = &mut self.vault;
+            vault.locked_lamports = vault.locked_lamports.checked_add(rent).ok_or(VaultError::Overflow)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Accounts needed to unwind a vault's auto-staked SOL.
+pub(crate) struct AutoStakeSettlement<'a, 'info> {
+    pub vault: &'a mut Account<'info, Vault>,
+    pub auto_stake: Option<&'a Account<'info, AutoStake>>,
+    pub stake_target: Option<&'a UncheckedAccount<'info>>,
+    pub stake_history: Option<&'a UncheckedAccount<'info>>,
+    pub stake_program: Option<&'a UncheckedAccount<'info>>,
+    pub clock: Option<&'a Sysvar<'info, Clock>>,
+    /// Receives the AutoStake record's rent once settled (None = the vault itself)
+    pub rent_destination: Option<AccountInfo<'info>>,
+}
+
+/// Unwind the vault's auto-staked SOL and record principal against rewards.
+/// Native stake is withdrawn back into locked SOL; a stake pool LST is valued at the
+/// pool's current rate and stays in locked tokens. Returns false while the stake
+/// is only starting to deactivate.
+pub(crate) fn settle_auto_stake(settlement: AutoStakeSettlement, clock: &Clock) -> Result<bool> {
+    let vault = settlement.vault;
+    let (Some(auto_stake), Some(stake_target)) = (settlement.auto_stake, settlement.stake_target) else {
+        return err!(VaultError::AutoStakeRequired);
+    };
+    require_keys_eq!(stake_target.key(), auto_stake.target, VaultError::AutoStakeAccountsRequired);
+
+    let value = match auto_stake.mode {
+        AutoStakeMode::NativeStake => {
+            let (Some(clock_sysvar), Some(stake_history), Some(_)) = (
+                settlement.clock,
+                settlement.stake_history,
+                settlement.stake_program,
+            ) else {
+                return err!(VaultError::AutoStakeAccountsRequired);
+            };
+
+            // PDA seeds for signing
+            let creator_key = vault.creator;
+            let vault_seed = vault.vault_seed;
+            let bump = vault.bump;
+
+            let seeds = &[
+                VAULT_SEED,
+                creator_key.as_ref(),
+                &vault_seed.to_le_bytes(),
+                &[bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            let state = StakeStateV2::deserialize(&mut &stake_target.try_borrow_data()?[..])?;
+            match state {
+                StakeStateV2::Stake(_, stake, _) if stake.delegation.deactivation_epoch == u64::MAX => {
+                    invoke_signed(
+                        &stake_instruction::deactivate_stake(&stake_target.key(), &vault.key()),
+                        &[
+                            stake_target.to_account_info(),
+                            clock_sysvar.to_account_info(),
+                            vault.to_account_info(),
+                        ],
+                        signer_seeds,
+                    )?;
+                    msg!("Auto-staked SOL deactivating; settle again after epoch {}", clock.epoch);
+                    return Ok(false);
+                }
+                StakeStateV2::Stake(_, stake, _) => {
+                    require!(
+                        clock.epoch > stake.delegation.deactivation_epoch,
+                        VaultError::StakeCoolingDown
+                    );
+                }
+                StakeStateV2::Initialized(_) => {}
+                _ => return err!(VaultError::InvalidStakeAccount),
+            }
+
+            let withdrawn = stake_target.lamports();
+            invoke_signed(
+                &stake_instruction::withdraw(
+                    &stake_target.key(),
+                    &vault.key(),
+                    &vault.key(),
+                    withdrawn,
+                    None,
+                ),
+                &[
+                    stake_target.to_account_info(),
+                    vault.to_account_info(),
+                    clock_sysvar.to_account_info(),
+                    stake_history.to_account_info(),
+                ],
+                signer_seeds,
+            )?;
+
+            vault.locked_lamports = vault
+                .locked_lamports
+                .checked_add(withdrawn)
+                .ok_or(VaultError::Overflow)?;
+            withdrawn
+        }
+        AutoStakeMode::StakePool => {
+            require_keys_eq!(*stake_target.owner, SPL_STAKE_POOL_PROGRAM_ID, VaultError::InvalidStakePool);
+            AutoStake::pool_value(stake_target, vault.locked_tokens)?
+        }
+    };
+
+    let principal = auto_stake.principal;
+    let rewards = value.saturating_sub(principal);
+
+    emit!(AutoStakeSettled {
+        vault: vault.key(),
+        target: stake_target.key(),
+        principal,
+        value,
+        rewards,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Auto-stake settled: principal {} lamports, rewards {} lamports", principal, rewards);
+
+    vault.has_auto_stake = false;
+    let rent_destination = settlement.rent_destination.unwrap_or_else(|| vault.to_account_info());
+    auto_stake.close(rent_destination)?;
+
+    Ok(true)
+}
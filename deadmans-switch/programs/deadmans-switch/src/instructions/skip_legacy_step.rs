@@ -0,0 +1,66 @@
+//! Skip a stored legacy instruction that cannot be executed.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SkipLegacyStep<'info> {
+    #[account(
+        mut,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::VaultDestroyed,
+        constraint = vault.has_legacy_plan @ VaultError::NoPendingLegacySteps,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [LEGACY_SEED, vault.key().as_ref()],
+        bump = legacy_plan.bump,
+    )]
+    pub legacy_plan: Account<'info, LegacyPlan>,
+
+    /// Anyone, once the skip delay has passed
+    pub caller: Signer<'info>,
+}
+
+impl<'info> SkipLegacyStep<'info> {
+    /// Handler for skip_legacy_step instruction.
+    /// Moves past a step that keeps failing (e.g. a closed account or a changed
+    /// multisig), so the plan can finish and the vault can be closed. The step's
+    /// `executed_at` records the negated skip time. The delay applies to every
+    /// caller, so a recipient can't skip a step before anyone has had time to run it.
+    pub fn handler(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        let vault = &mut self.vault;
+        let plan = &mut self.legacy_plan;
+
+        let skip_open_at = vault
+            .released_at
+            .checked_add(LEGACY_SKIP_DELAY)
+            .ok_or(VaultError::Overflow)?;
+        require!(clock.unix_timestamp >= skip_open_at, VaultError::LegacySkipTooEarly);
+
+        let index = plan.next_step as usize;
+        let step = plan.steps.get_mut(index).ok_or(VaultError::NoPendingLegacySteps)?;
+        step.executed_at = -clock.unix_timestamp;
+        let program_id = step.program_id;
+        plan.next_step += 1;
+
+        if plan.next_step as usize == plan.steps.len() {
+            vault.has_legacy_plan = false;
+        }
+
+        emit!(LegacyStepSkipped {
+            vault: vault.key(),
+            step_index: index as u8,
+            program_id,
+            skipped_by: self.caller.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Legacy step {} skipped", index);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//! Snooze (vacation mode): hold the deadline until a specific timestamp.
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct Snooze<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> Snooze<'info> {
+    /// Handler for snooze instruction.
+    /// The snooze stays recorded until the next regular ping restores the normal interval.
+    pub fn handler(&mut self, until: i64) -> Result<()> {
+        let vault = &mut self.vault;
+        let clock = Clock::get()?;
+
+        require!(!vault.is_released, VaultError::AlreadyReleased);
+        require!(
+            vault.release_mode != ReleaseMode::FixedDate,
+            VaultError::ReleaseModeMismatch
+        );
+        vault.require_own_heartbeat()?;
+        vault.require_no_hash_chain()?;
+
+        let max_until = clock
+            .unix_timestamp
+            .checked_add(vault.snooze_limit())
+            .ok_or(VaultError::Overflow)?;
+        require!(
+            until > clock.unix_timestamp && until <= max_until,
+            VaultError::InvalidSnooze
+        );
+
+        vault.last_check_in = clock.unix_timestamp;
+        vault.snoozed_until = until;
+
+        msg!("Vault snoozed until: {}", until);
+
+        Ok(())
+    }
+}
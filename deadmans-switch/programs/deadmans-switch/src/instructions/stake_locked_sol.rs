@@ -0,0 +1,171 @@
+//! Delegate locked SOL from a native stake account controlled by the vault.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::system_program::{self, CreateAccount};
+use solana_stake_interface::{
+    instruction as stake_instruction,
+    state::{Authorized, Lockup, StakeStateV2},
+};
+use crate::{constants::*, errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct StakeLockedSol<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+        constraint = !vault.has_auto_stake @ VaultError::AutoStakeActive,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::ReleaseActionConflict,
+        constraint = vault.locked_lamports > 0 @ VaultError::NoLockedSol,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Pays rent for the AutoStake record and the stake account's reserve
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = AutoStake::SPACE,
+        seeds = [AUTO_STAKE_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub auto_stake: Account<'info, AutoStake>,
+
+    /// CHECK: Native stake account created here, with the vault as staker and withdrawer
+    #[account(
+        mut,
+        seeds = [AUTO_STAKE_SEED, vault.key().as_ref(), b"native"],
+        bump,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validator vote account, verified by the stake program
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: Stake history sysvar, verified by the stake program
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: Stake config account, still required by DelegateStake
+    pub stake_config: UncheckedAccount<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    /// CHECK: Native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeLockedSol<'info> {
+    /// Handler for stake_locked_sol instruction.
+    /// Moves all locked SOL into a fresh stake account delegated to `vote_account`.
+    /// Rewards compound in the stake account until `claim_sol` settles it after release.
+    pub fn handler(&mut self, co_signers: &[AccountInfo], auto_stake_bump: u8, stake_bump: u8) -> Result<()> {
+        self.vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::StakeLockedSol,
+            &self.vote_account.key(),
+        )?;
+
+        let vault_key = self.vault.key();
+        let stake_seeds = &[AUTO_STAKE_SEED, vault_key.as_ref(), b"native", &[stake_bump]];
+
+        let reserve = self.rent.minimum_balance(StakeStateV2::size_of());
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.owner.to_account_info(),
+                    to: self.stake_account.to_account_info(),
+                },
+                &[&stake_seeds[..]],
+            ),
+            reserve,
+            StakeStateV2::size_of() as u64,
+            &solana_stake_interface::program::ID,
+        )?;
+
+        // Vault PDA can transfer its own lamports without signing
+        let amount = self.vault.locked_lamports;
+        **self.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **self.stake_account.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        invoke(
+            &stake_instruction::initialize(
+                &self.stake_account.key(),
+                &Authorized {
+                    staker: vault_key,
+                    withdrawer: vault_key,
+                },
+                &Lockup::default(),
+            ),
+            &[self.stake_account.to_account_info(), self.rent.to_account_info()],
+        )?;
+
+        // PDA seeds for signing
+        let creator_key = self.vault.creator;
+        let vault_seed = self.vault.vault_seed;
+        let bump = self.vault.bump;
+
+        let seeds = &[
+            VAULT_SEED,
+            creator_key.as_ref(),
+            &vault_seed.to_le_bytes(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        invoke_signed(
+            &stake_instruction::delegate_stake(
+                &self.stake_account.key(),
+                &vault_key,
+                &self.vote_account.key(),
+            ),
+            &[
+                self.stake_account.to_account_info(),
+                self.vote_account.to_account_info(),
+                self.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                self.stake_config.to_account_info(),
+                self.vault.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        let auto_stake = &mut self.auto_stake;
+        auto_stake.vault = vault_key;
+        auto_stake.mode = AutoStakeMode::NativeStake;
+        auto_stake.target = self.stake_account.key();
+        auto_stake.principal = amount + reserve;
+        auto_stake.bump = auto_stake_bump;
+
+        let vault = &mut self.vault;
+        vault.locked_lamports = 0;
+        vault.has_auto_stake = true;
+
+        emit!(SolAutoStaked {
+            vault: vault_key,
+            target: self.stake_account.key(),
+            principal: amount + reserve,
+            timestamp: self.clock.unix_timestamp,
+        });
+
+        msg!(
+            "Staked {} locked lamports with validator {}",
+            amount,
+            self.vote_account.key()
+        );
+
+        Ok(())
+    }
+}
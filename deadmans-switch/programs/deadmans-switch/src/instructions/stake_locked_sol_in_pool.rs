@@ -0,0 +1,181 @@
+//! Deposit locked SOL into an SPL stake pool for its liquid staking token.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// StakePoolInstruction::DepositSol discriminant
+const DEPOSIT_SOL: u8 = 14;
+
+#[derive(Accounts)]
+pub struct StakeLockedSolInPool<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+        constraint = !vault.has_auto_stake @ VaultError::AutoStakeActive,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::ReleaseActionConflict,
+        constraint = vault.locked_lamports > 0 @ VaultError::NoLockedSol,
+        constraint = vault.token_mint.is_none() @ VaultError::TokensAlreadyLocked,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = AutoStake::SPACE,
+        seeds = [AUTO_STAKE_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub auto_stake: Account<'info, AutoStake>,
+
+    /// CHECK: Data-less PDA that funds the deposit; the stake pool pulls SOL with a system transfer
+    #[account(
+        mut,
+        seeds = [AUTO_STAKE_SEED, vault.key().as_ref(), b"escrow"],
+        bump,
+    )]
+    pub sol_escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Stake pool, verified by the stake pool program
+    #[account(mut, owner = SPL_STAKE_POOL_PROGRAM_ID @ VaultError::InvalidStakePool)]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Stake pool withdraw authority, verified by the stake pool program
+    pub stake_pool_withdraw_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Stake pool reserve stake account, verified by the stake pool program
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+
+    /// CHECK: Stake pool manager fee account, verified by the stake pool program
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+
+    /// The pool's liquid staking token
+    #[account(mut)]
+    pub pool_mint: Account<'info, Mint>,
+
+    /// Vault's LST account; also receives the referral fee so it stays in the estate
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = pool_mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    /// CHECK: SPL stake pool program
+    #[account(address = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeLockedSolInPool<'info> {
+    /// Handler for stake_locked_sol_in_pool instruction.
+    /// Deposits all locked SOL into the stake pool. The LST becomes the vault's locked
+    /// tokens and is claimed with `claim_tokens`; `claim_sol` settles the principal and rewards.
+    pub fn handler(&mut self, co_signers: &[AccountInfo], auto_stake_bump: u8, escrow_bump: u8) -> Result<()> {
+        self.vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::StakeLockedSol,
+            &self.stake_pool.key(),
+        )?;
+
+        // Vault PDA can transfer its own lamports without signing
+        let amount = self.vault.locked_lamports;
+        **self.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **self.sol_escrow.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        // Deposit everything in the escrow so it is left empty
+        let deposit = self.sol_escrow.lamports();
+
+        let mut data = vec![DEPOSIT_SOL];
+        data.extend_from_slice(&deposit.to_le_bytes());
+        let instruction = Instruction {
+            program_id: SPL_STAKE_POOL_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(self.stake_pool.key(), false),
+                AccountMeta::new_readonly(self.stake_pool_withdraw_authority.key(), false),
+                AccountMeta::new(self.reserve_stake.key(), false),
+                AccountMeta::new(self.sol_escrow.key(), true),
+                AccountMeta::new(self.vault_token_account.key(), false),
+                AccountMeta::new(self.manager_fee_account.key(), false),
+                AccountMeta::new(self.vault_token_account.key(), false),
+                AccountMeta::new(self.pool_mint.key(), false),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+                AccountMeta::new_readonly(self.token_program.key(), false),
+            ],
+            data,
+        };
+
+        let vault_key = self.vault.key();
+        let escrow_seeds = &[AUTO_STAKE_SEED, vault_key.as_ref(), b"escrow", &[escrow_bump]];
+        invoke_signed(
+            &instruction,
+            &[
+                self.stake_pool.to_account_info(),
+                self.stake_pool_withdraw_authority.to_account_info(),
+                self.reserve_stake.to_account_info(),
+                self.sol_escrow.to_account_info(),
+                self.vault_token_account.to_account_info(),
+                self.manager_fee_account.to_account_info(),
+                self.pool_mint.to_account_info(),
+                self.system_program.to_account_info(),
+                self.token_program.to_account_info(),
+            ],
+            &[&escrow_seeds[..]],
+        )?;
+
+        self.vault_token_account.reload()?;
+        let pool_tokens = self.vault_token_account.amount;
+
+        let auto_stake = &mut self.auto_stake;
+        auto_stake.vault = vault_key;
+        auto_stake.mode = AutoStakeMode::StakePool;
+        auto_stake.target = self.stake_pool.key();
+        auto_stake.principal = deposit;
+        auto_stake.bump = auto_stake_bump;
+
+        let vault = &mut self.vault;
+        vault.locked_lamports = 0;
+        vault.token_mint = Some(self.pool_mint.key());
+        vault.locked_tokens = pool_tokens;
+        vault.has_auto_stake = true;
+
+        emit!(SolAutoStaked {
+            vault: vault_key,
+            target: self.stake_pool.key(),
+            principal: deposit,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Deposited {} lamports into stake pool {} for {} pool tokens",
+            deposit,
+            self.stake_pool.key(),
+            pool_tokens
+        );
+
+        Ok(())
+    }
+}
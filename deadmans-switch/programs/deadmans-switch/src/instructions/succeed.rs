@@ -0,0 +1,118 @@
+//! Succession: the recipient inherits a released vault itself instead of draining it.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct Succeed<'info> {
+    #[account(
+        mut,
+        constraint = vault.recipient == heir.key() @ VaultError::NotRecipient,
+        constraint = vault.is_released @ VaultError::NotReleased,
+        constraint = !vault.has_escalation_plan && !vault.has_allocation_table @ VaultError::SuccessionBlocked,
+        constraint = vault.release_action == ReleaseAction::Release @ VaultError::VaultDestroyed,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub heir: Signer<'info>,
+
+    /// Estate the vault is linked to; it belongs to the previous owner, so the vault is unlinked
+    #[account(mut)]
+    pub estate: Option<Account<'info, Estate>>,
+
+    /// The previous owner's stored instructions (required if still pending); closed to the heir
+    #[account(
+        mut,
+        close = heir,
+        seeds = [LEGACY_SEED, vault.key().as_ref()],
+        bump = legacy_plan.bump,
+    )]
+    pub legacy_plan: Option<Account<'info, LegacyPlan>>,
+}
+
+impl<'info> Succeed<'info> {
+    /// Handler for succeed instruction.
+    /// The heir becomes the sole owner with a fresh heartbeat, and must name the next
+    /// recipient and payload. Locked assets, the interval, the bounty and the notification
+    /// pool stay in place; every other owner-side setting returns to its default and any
+    /// stored legacy instructions are discarded.
+    pub fn handler(
+        &mut self,
+        new_recipient: Pubkey,
+        ipfs_cid: String,
+        encrypted_key: String,
+    ) -> Result<()> {
+        require!(ipfs_cid.len() <= MAX_IPFS_CID_LEN, VaultError::IpfsCidTooLong);
+        require!(
+            encrypted_key.len() <= MAX_ENCRYPTED_KEY_LEN,
+            VaultError::EncryptedKeyTooLong
+        );
+        require_keys_neq!(new_recipient, self.heir.key(), VaultError::InvalidSuccessorRecipient);
+
+        let vault = &mut self.vault;
+        let clock = Clock::get()?;
+        vault.require_claim_open(clock.unix_timestamp)?;
+
+        // Stored instructions must not be signed by the heir's vault after the next release
+        if vault.has_legacy_plan {
+            require!(self.legacy_plan.is_some(), VaultError::LegacyPlanRequired);
+            vault.has_legacy_plan = false;
+        }
+
+        if vault.linked_estate(self.estate.as_ref())?.is_some() {
+            if let Some(estate) = self.estate.as_mut() {
+                estate.linked_vaults = estate.linked_vaults.saturating_sub(1);
+            }
+            vault.estate = None;
+        }
+
+        let previous_owner = vault.owner;
+        vault.owner = self.heir.key();
+        vault.recipient = new_recipient;
+        vault.ipfs_cid = ipfs_cid;
+        vault.encrypted_key = encrypted_key;
+        vault.last_check_in = clock.unix_timestamp;
+        vault.is_released = false;
+        vault.released_at = 0;
+        vault.delegate = None;
+
+        // The previous owner's co-signers, schedules and restrictions don't carry over
+        vault.co_owners = Vec::new();
+        vault.survivor_policy = SurvivorPolicy::All;
+        vault.admin_threshold = 1;
+        vault.authorities = Vec::new();
+        vault.snoozed_until = 0;
+        vault.max_snooze = 0;
+        vault.release_mode = ReleaseMode::Heartbeat;
+        vault.release_at = 0;
+        vault.claim_not_before = 0;
+        vault.minor_guardian = None;
+        vault.guardian_destination = None;
+        vault.claim_deadline = 0;
+        vault.fallback_recipients = Vec::new();
+        vault.custody_log = Vec::new();
+        vault.is_irrevocable = false;
+        vault.hash_chain_head = None;
+        vault.hash_chain_remaining = 0;
+        vault.liveness_adapters = Vec::new();
+        vault.warning_window = 0;
+        vault.warned_at = 0;
+        vault.notification_bounty = 0;
+        vault.release_notice_lamports = 0;
+        vault.release_notice_memo = String::new();
+
+        emit!(VaultSucceeded {
+            vault: vault.key(),
+            previous_owner,
+            new_owner: vault.owner,
+            new_recipient,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Vault inherited in succession: {} -> {}", previous_owner, vault.owner);
+        msg!("Next recipient: {}", new_recipient);
+
+        Ok(())
+    }
+}
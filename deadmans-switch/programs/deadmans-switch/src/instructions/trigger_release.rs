@@ -1,7 +1,11 @@
 //! Trigger the release of vault contents if the timer has expired.
 
 use anchor_lang::prelude::*;
-use crate::{errors::*, state::*};
+use anchor_spl::{
+    memo::{self, BuildMemo, Memo},
+    token::{self, Burn, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{constants::*, errors::*, events::*, state::*};
 
 #[derive(Accounts)]
 pub struct TriggerRelease<'info> {
@@ -11,45 +15,282 @@ pub struct TriggerRelease<'info> {
     /// The hunter who triggers the release and receives the bounty
     #[account(mut)]
     pub hunter: Signer<'info>,
+
+    /// Estate providing the shared heartbeat (required only for linked vaults)
+    pub estate: Option<Account<'info, Estate>>,
+
+    /// Escalation plan (required only for vaults with staged tiers)
+    #[account(
+        mut,
+        seeds = [ESCALATION_SEED, vault.key().as_ref()],
+        bump = escalation_plan.bump,
+    )]
+    pub escalation_plan: Option<Account<'info, EscalationPlan>>,
+
+    /// CHECK: Vault owner receiving returned SOL (destroy actions only)
+    #[account(mut, address = vault.owner @ VaultError::Unauthorized)]
+    pub owner: Option<UncheckedAccount<'info>>,
+
+    /// Locked token mint (destroy actions with locked tokens only)
+    #[account(mut)]
+    pub token_mint: Option<Account<'info, Mint>>,
+
+    /// Vault's token account holding the locked tokens
+    #[account(mut, token::authority = vault)]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Owner's token account receiving returned tokens
+    #[account(mut, token::authority = vault.owner)]
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// CHECK: Vault recipient receiving the release notice (only if the vault sends one)
+    #[account(mut, address = vault.recipient @ VaultError::NotRecipient)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    pub memo_program: Option<Program<'info, Memo>>,
 }
 
 impl<'info> TriggerRelease<'info> {
     /// Handler for trigger_release instruction.
+    /// Escalation tiers are released one per call, as each becomes due;
+    /// otherwise the vault itself is released (or destroyed) once its deadline passes.
     pub fn handler(&mut self) -> Result<()> {
         let vault = &mut self.vault;
         let clock = Clock::get()?;
+        let estate = vault.linked_estate(self.estate.as_ref())?;
+
+        if vault.has_escalation_plan {
+            let plan = self
+                .escalation_plan
+                .as_mut()
+                .ok_or(VaultError::EscalationPlanRequired)?;
+
+            let last_activity = vault.last_activity(estate);
+            if let Some(index) = plan.next_due_tier(last_activity, vault.snoozed_until, clock.unix_timestamp)? {
+                let tier = &mut plan.tiers[index];
+                let bounty = tier.bounty_lamports;
+
+                pay_bounty(vault, &self.hunter, bounty)?;
+                tier.bounty_lamports = 0;
+                tier.released_at = clock.unix_timestamp;
+                plan.next_tier += 1;
+
+                emit!(TierReleased {
+                    vault: vault.key(),
+                    tier_index: index as u8,
+                    beneficiary: plan.tiers[index].beneficiary,
+                    bounty_lamports: bounty,
+                    timestamp: clock.unix_timestamp,
+                });
+
+                msg!(
+                    "Tier {} released! Beneficiary {} can now claim.",
+                    index,
+                    plan.tiers[index].beneficiary
+                );
+
+                return Ok(());
+            }
+        }
 
         require!(!vault.is_released, VaultError::AlreadyReleased);
 
-        let expiry_time = vault
-            .last_check_in
-            .checked_add(vault.time_interval)
-            .ok_or(VaultError::Overflow)?;
+        let release_time = vault.release_time(estate)?;
 
-        require!(clock.unix_timestamp > expiry_time, VaultError::NotExpired);
+        require!(clock.unix_timestamp > release_time, VaultError::NotExpired);
 
-        // Pay bounty to hunter
+        // Pay bounty to hunter, less the recipient's release notice
         let bounty = vault.bounty_lamports;
-        if bounty > 0 {
-            // Check rent exemption before transferring bounty
-            let vault_lamports = vault.to_account_info().lamports();
-            let rent = Rent::get()?;
-            let min_rent = rent.minimum_balance(Vault::SPACE);
+        let notice = if vault.release_action == ReleaseAction::Release {
+            vault.release_notice_lamports.min(bounty)
+        } else {
+            0
+        };
+        pay_bounty(vault, &self.hunter, bounty - notice)?;
+        vault.bounty_lamports = 0;
+
+        vault.mark_released(clock.unix_timestamp);
+
+        if vault.release_action != ReleaseAction::Release {
+            return self.destroy_contents(bounty, clock.unix_timestamp);
+        }
+
+        emit!(VaultReleased {
+            vault: vault.key(),
+            recipient: vault.recipient,
+            released_by: self.hunter.key(),
+            bounty_lamports: bounty - notice,
+            bounty_to_recipient: 0,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Vault released! Recipient {} can now claim.", vault.recipient);
+
+        if vault.sends_release_notice() {
+            self.notify_recipient(notice)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info> TriggerRelease<'info> {
+    /// Send the recipient a small lamport transfer and a memo so the release shows
+    /// up in their wallet history. Brand-new wallets only get the memo if the
+    /// amount is below the rent-exempt minimum; the unsent amount is then left
+    /// with the locked SOL for the recipient to claim.
+    fn notify_recipient(&mut self, lamports: u64) -> Result<()> {
+        let (Some(recipient), Some(memo_program)) = (self.recipient.as_ref(), self.memo_program.as_ref()) else {
+            return err!(VaultError::NoticeAccountsRequired);
+        };
+        let vault = &mut self.vault;
+
+        let min_rent = Rent::get()?.minimum_balance(0);
+        if recipient.lamports() > 0 || lamports >= min_rent {
+            **vault.to_account_info().try_borrow_mut_lamports()? -= lamports;
+            **recipient.to_account_info().try_borrow_mut_lamports()? += lamports;
+        } else {
+            vault.locked_lamports = vault
+                .locked_lamports
+                .checked_add(lamports)
+                .ok_or(VaultError::Overflow)?;
+        }
+
+        let text = if vault.release_notice_memo.is_empty() {
+            format!("Vault '{}' has been released to you", vault.name)
+        } else {
+            format!("Vault '{}': {}", vault.name, vault.release_notice_memo)
+        };
+        memo::build_memo(
+            CpiContext::new(memo_program.to_account_info(), BuildMemo {}),
+            text.as_bytes(),
+        )?;
+
+        msg!("Release notice sent to recipient {}", recipient.key());
+
+        Ok(())
+    }
+
+    /// Wipe the payload and dispose of locked assets instead of releasing them.
+    /// Locked SOL returns to the owner; locked tokens are burned or returned.
+    fn destroy_contents(&mut self, bounty: u64, now: i64) -> Result<()> {
+        let vault = &mut self.vault;
+        let ipfs_cid = std::mem::take(&mut vault.ipfs_cid);
+        vault.encrypted_key = String::new();
+
+        let lamports_returned = vault.locked_lamports;
+        if lamports_returned > 0 {
+            let owner = self.owner.as_ref().ok_or(VaultError::DestroyAccountsRequired)?;
+            **vault.to_account_info().try_borrow_mut_lamports()? -= lamports_returned;
+            **owner.to_account_info().try_borrow_mut_lamports()? += lamports_returned;
+            vault.locked_lamports = 0;
+        }
+
+        let mut tokens_burned = 0;
+        let mut tokens_returned = 0;
+        if vault.locked_tokens > 0 {
+            let (Some(token_mint), Some(vault_token_account), Some(token_program)) = (
+                self.token_mint.as_ref(),
+                self.vault_token_account.as_ref(),
+                self.token_program.as_ref(),
+            ) else {
+                return err!(VaultError::DestroyAccountsRequired);
+            };
             require!(
-                vault_lamports.saturating_sub(bounty) >= min_rent,
-                VaultError::InsufficientBalance
+                vault.token_mint == Some(token_mint.key())
+                    && vault_token_account.mint == token_mint.key(),
+                VaultError::InvalidMint
             );
 
-            **vault.to_account_info().try_borrow_mut_lamports()? -= bounty;
-            **self.hunter.to_account_info().try_borrow_mut_lamports()? += bounty;
-            vault.bounty_lamports = 0;
-            msg!("Bounty of {} lamports paid to hunter: {}", bounty, self.hunter.key());
+            let amount = vault.locked_tokens;
+            let creator_key = vault.creator;
+            let vault_seed = vault.vault_seed;
+            let bump = vault.bump;
+
+            let seeds = &[
+                VAULT_SEED,
+                creator_key.as_ref(),
+                &vault_seed.to_le_bytes(),
+                &[bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            if vault.release_action == ReleaseAction::DestroyBurnTokens {
+                token::burn(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Burn {
+                            mint: token_mint.to_account_info(),
+                            from: vault_token_account.to_account_info(),
+                            authority: vault.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )?;
+                tokens_burned = amount;
+            } else {
+                let owner_token_account = self
+                    .owner_token_account
+                    .as_ref()
+                    .ok_or(VaultError::DestroyAccountsRequired)?;
+                require_keys_eq!(owner_token_account.mint, token_mint.key(), VaultError::InvalidMint);
+
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: vault_token_account.to_account_info(),
+                            to: owner_token_account.to_account_info(),
+                            authority: vault.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )?;
+                tokens_returned = amount;
+            }
+
+            vault.locked_tokens = 0;
         }
 
-        vault.is_released = true;
+        emit!(VaultDestroyed {
+            vault: vault.key(),
+            ipfs_cid,
+            hunter: self.hunter.key(),
+            bounty_lamports: bounty,
+            lamports_returned,
+            tokens_burned,
+            tokens_returned,
+            timestamp: now,
+        });
 
-        msg!("Vault released! Recipient {} can now claim.", vault.recipient);
+        msg!("Vault destroyed on expiry. Payload wiped.");
 
         Ok(())
     }
 }
+
+/// Move a bounty from the vault PDA to the hunter, keeping the vault rent-exempt.
+fn pay_bounty(vault: &Account<Vault>, hunter: &Signer, bounty: u64) -> Result<()> {
+    if bounty == 0 {
+        return Ok(());
+    }
+
+    // Check rent exemption before transferring bounty
+    let vault_lamports = vault.to_account_info().lamports();
+    let rent = Rent::get()?;
+    let min_rent = rent.minimum_balance(Vault::SPACE);
+    require!(
+        vault_lamports.saturating_sub(bounty) >= min_rent,
+        VaultError::InsufficientBalance
+    );
+
+    **vault.to_account_info().try_borrow_mut_lamports()? -= bounty;
+    **hunter.to_account_info().try_borrow_mut_lamports()? += bounty;
+    msg!("Bounty of {} lamports paid to hunter: {}", bounty, hunter.key());
+
+    Ok(())
+}
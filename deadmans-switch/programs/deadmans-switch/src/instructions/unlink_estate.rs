@@ -0,0 +1,44 @@
+//! Unlink a vault from its estate so it follows its own timer again.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct UnlinkEstate<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+        constraint = vault.estate == Some(estate.key()) @ VaultError::NotLinked,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [ESTATE_SEED, owner.key().as_ref()],
+        bump = estate.bump,
+        has_one = owner @ VaultError::Unauthorized
+    )]
+    pub estate: Account<'info, Estate>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> UnlinkEstate<'info> {
+    /// Handler for unlink_estate instruction.
+    pub fn handler(&mut self) -> Result<()> {
+        let vault = &mut self.vault;
+        let estate = &mut self.estate;
+        let clock = Clock::get()?;
+
+        vault.estate = None;
+        // The vault's own timer may be long stale; the owner signing here is a check-in.
+        vault.last_check_in = clock.unix_timestamp;
+        estate.linked_vaults = estate.linked_vaults.saturating_sub(1);
+
+        msg!("Vault '{}' unlinked from estate {}", vault.name, estate.key());
+        msg!("Timer reset to: {}", vault.last_check_in);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,266 @@
+//! Unwind auto-staked SOL back into plain locked SOL while the vault is active.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount};
+use crate::{constants::*, errors::*, events::*, state::*};
+use super::settle_auto_stake::{settle_auto_stake, AutoStakeSettlement};
+
+/// StakePoolInstruction::WithdrawSol discriminant
+const WITHDRAW_SOL: u8 = 16;
+
+#[derive(Accounts)]
+pub struct UnstakeLockedSol<'info> {
+    #[account(
+        mut,
+        has_one = owner @ VaultError::Unauthorized,
+        constraint = !vault.is_released @ VaultError::AlreadyReleased,
+        constraint = vault.has_auto_stake @ VaultError::AutoStakeRequired,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Receives the AutoStake record's rent
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUTO_STAKE_SEED, vault.key().as_ref()],
+        bump = auto_stake.bump,
+    )]
+    pub auto_stake: Account<'info, AutoStake>,
+
+    /// CHECK: The auto-stake target (native stake account or stake pool), checked against the AutoStake record
+    #[account(mut)]
+    pub stake_target: UncheckedAccount<'info>,
+
+    /// CHECK: Stake history sysvar, verified by the stake program
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: Native stake program
+    #[account(address = solana_stake_interface::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// CHECK: Data-less PDA that receives the redeemed SOL (stake pool only)
+    #[account(
+        mut,
+        seeds = [AUTO_STAKE_SEED, vault.key().as_ref(), b"escrow"],
+        bump,
+    )]
+    pub sol_escrow: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Stake pool withdraw authority (stake pool only), verified by the stake pool program
+    pub stake_pool_withdraw_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Stake pool reserve stake account (stake pool only), verified by the stake pool program
+    #[account(mut)]
+    pub reserve_stake: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Stake pool manager fee account (stake pool only), verified by the stake pool program
+    #[account(mut)]
+    pub manager_fee_account: Option<UncheckedAccount<'info>>,
+
+    /// The pool's liquid staking token (stake pool only)
+    #[account(mut)]
+    pub pool_mint: Option<Account<'info, Mint>>,
+
+    /// Vault's LST account (stake pool only); closed to the owner once redeemed
+    #[account(mut)]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: SPL stake pool program (stake pool only)
+    #[account(address = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UnstakeLockedSol<'info> {
+    /// Handler for unstake_locked_sol instruction.
+    /// A delegated stake account is deactivated on the first call and withdrawn into
+    /// locked SOL once the cooldown has passed; a stake pool LST is redeemed for SOL.
+    pub fn handler(&mut self, co_signers: &[AccountInfo], escrow_bump: Option<u8>) -> Result<()> {
+        self.vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::UnstakeLockedSol,
+            &self.stake_target.key(),
+        )?;
+
+        let clock = Clock::get()?;
+        match self.auto_stake.mode {
+            AutoStakeMode::NativeStake => {
+                settle_auto_stake(
+                    AutoStakeSettlement {
+                        vault: &mut self.vault,
+                        auto_stake: Some(&self.auto_stake),
+                        stake_target: Some(&self.stake_target),
+                        stake_history: Some(&self.stake_history),
+                        stake_program: Some(&self.stake_program),
+                        clock: Some(&self.clock),
+                        rent_destination: Some(self.owner.to_account_info()),
+                    },
+                    &clock,
+                )?;
+            }
+            AutoStakeMode::StakePool => self.redeem_pool_tokens(escrow_bump, &clock)?,
+        }
+
+        Ok(())
+    }
+
+    /// Burn the vault's LST for SOL from the pool reserve, then return it to locked SOL.
+    fn redeem_pool_tokens(&mut self, escrow_bump: Option<u8>, clock: &Clock) -> Result<()> {
+        let (
+            Some(sol_escrow),
+            Some(escrow_bump),
+            Some(withdraw_authority),
+            Some(reserve_stake),
+            Some(manager_fee_account),
+            Some(pool_mint),
+            Some(vault_token_account),
+            Some(_),
+            Some(token_program),
+        ) = (
+            self.sol_escrow.as_ref(),
+            escrow_bump,
+            self.stake_pool_withdraw_authority.as_ref(),
+            self.reserve_stake.as_ref(),
+            self.manager_fee_account.as_ref(),
+            self.pool_mint.as_ref(),
+            self.vault_token_account.as_mut(),
+            self.stake_pool_program.as_ref(),
+            self.token_program.as_ref(),
+        ) else {
+            return err!(VaultError::AutoStakeAccountsRequired);
+        };
+
+        let vault = &mut self.vault;
+        let stake_pool = &self.stake_target;
+        require_keys_eq!(stake_pool.key(), self.auto_stake.target, VaultError::AutoStakeAccountsRequired);
+        require_keys_eq!(*stake_pool.owner, SPL_STAKE_POOL_PROGRAM_ID, VaultError::InvalidStakePool);
+        require!(vault.token_mint == Some(pool_mint.key()), VaultError::InvalidMint);
+        require!(
+            vault_token_account.mint == pool_mint.key() && vault_token_account.owner == vault.key(),
+            VaultError::InvalidMint
+        );
+
+        // PDA seeds for signing
+        let creator_key = vault.creator;
+        let vault_seed = vault.vault_seed;
+        let bump = vault.bump;
+
+        let seeds = &[
+            VAULT_SEED,
+            creator_key.as_ref(),
+            &vault_seed.to_le_bytes(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let pool_tokens = vault.locked_tokens;
+        let mut data = vec![WITHDRAW_SOL];
+        data.extend_from_slice(&pool_tokens.to_le_bytes());
+        let instruction = Instruction {
+            program_id: SPL_STAKE_POOL_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(stake_pool.key(), false),
+                AccountMeta::new_readonly(withdraw_authority.key(), false),
+                AccountMeta::new_readonly(vault.key(), true),
+                AccountMeta::new(vault_token_account.key(), false),
+                AccountMeta::new(reserve_stake.key(), false),
+                AccountMeta::new(sol_escrow.key(), false),
+                AccountMeta::new(manager_fee_account.key(), false),
+                AccountMeta::new(pool_mint.key(), false),
+                AccountMeta::new_readonly(self.clock.key(), false),
+                AccountMeta::new_readonly(self.stake_history.key(), false),
+                AccountMeta::new_readonly(self.stake_program.key(), false),
+                AccountMeta::new_readonly(token_program.key(), false),
+            ],
+            data,
+        };
+        invoke_signed(
+            &instruction,
+            &[
+                stake_pool.to_account_info(),
+                withdraw_authority.to_account_info(),
+                vault.to_account_info(),
+                vault_token_account.to_account_info(),
+                reserve_stake.to_account_info(),
+                sol_escrow.to_account_info(),
+                manager_fee_account.to_account_info(),
+                pool_mint.to_account_info(),
+                self.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                self.stake_program.to_account_info(),
+                token_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        // Move everything the escrow received into the vault so it is left empty
+        let value = sol_escrow.lamports();
+        let vault_key = vault.key();
+        let escrow_seeds = &[AUTO_STAKE_SEED, vault_key.as_ref(), b"escrow", &[escrow_bump]];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: sol_escrow.to_account_info(),
+                    to: vault.to_account_info(),
+                },
+                &[&escrow_seeds[..]],
+            ),
+            value,
+        )?;
+
+        vault_token_account.reload()?;
+        if vault_token_account.amount == 0 {
+            token::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: vault_token_account.to_account_info(),
+                    destination: self.owner.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+
+        vault.locked_lamports = vault.locked_lamports.checked_add(value).ok_or(VaultError::Overflow)?;
+        vault.locked_tokens = 0;
+        vault.token_mint = None;
+        vault.has_auto_stake = false;
+
+        let principal = self.auto_stake.principal;
+        let rewards = value.saturating_sub(principal);
+
+        emit!(AutoStakeSettled {
+            vault: vault_key,
+            target: stake_pool.key(),
+            principal,
+            value,
+            rewards,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Redeemed {} pool tokens for {} lamports", pool_tokens, value);
+
+        self.auto_stake.close(self.owner.to_account_info())?;
+
+        Ok(())
+    }
+}
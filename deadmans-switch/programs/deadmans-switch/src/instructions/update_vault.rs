@@ -1,4 +1,4 @@
-//! Update vault settings (recipient, interval, name).
+//! Update vault settings (recipient, interval, name, snooze cap, release date).
 
 use anchor_lang::prelude::*;
 use crate::{constants::*, errors::*, state::*};
@@ -12,27 +12,50 @@ pub struct UpdateVault<'info> {
     pub vault: Account<'info, Vault>,
 
     pub owner: Signer<'info>,
+
+    /// Proposal carrying approvals collected in earlier transactions
+    #[account(mut, has_one = vault @ VaultError::ProposalMismatch)]
+    pub proposal: Option<Account<'info, Proposal>>,
 }
 
 impl<'info> UpdateVault<'info> {
     /// Handler for update_vault instruction.
+    /// The allocation table is edited with set_allocations instead: it lives in its own
+    /// PDA, and keeping it out of here leaves this instruction's accounts and proposal
+    /// arguments unchanged for existing clients.
     pub fn handler(
         &mut self,
+        co_signers: &[AccountInfo],
         new_recipient: Option<Pubkey>,
         new_time_interval: Option<i64>,
         new_name: Option<String>,
+        new_max_snooze: Option<i64>,
+        new_release_at: Option<i64>,
     ) -> Result<()> {
         let vault = &mut self.vault;
 
         require!(!vault.is_released, VaultError::AlreadyReleased);
+        vault.require_admin_approval(
+            &self.owner.key(),
+            co_signers,
+            self.proposal.as_mut(),
+            AdminAction::UpdateVault,
+            &(&new_recipient, &new_time_interval, &new_name, &new_max_snooze, &new_release_at),
+        )?;
 
         if let Some(recipient) = new_recipient {
+            vault.require_revocable()?;
             vault.recipient = recipient;
             msg!("Recipient updated to: {}", recipient);
         }
 
         if let Some(interval) = new_time_interval {
             require!(interval > 0, VaultError::InvalidTimeInterval);
+            // Irrevocable vaults may only push the release further out
+            require!(
+                !vault.is_irrevocable || interval >= vault.time_interval,
+                VaultError::VaultIrrevocable
+            );
             vault.time_interval = interval;
             msg!("Time interval updated to: {} seconds", interval);
         }
@@ -43,6 +66,25 @@ impl<'info> UpdateVault<'info> {
             msg!("Name updated to: {}", name);
         }
 
+        if let Some(max_snooze) = new_max_snooze {
+            require!(
+                (0..=MAX_SNOOZE_DURATION).contains(&max_snooze),
+                VaultError::InvalidSnooze
+            );
+            vault.max_snooze = max_snooze;
+            msg!("Max snooze updated to: {} seconds", max_snooze);
+        }
+
+        if let Some(release_at) = new_release_at {
+            require!(
+                vault.release_mode != ReleaseMode::Heartbeat,
+                VaultError::ReleaseModeMismatch
+            );
+            require!(release_at >= vault.release_at, VaultError::InvalidReleaseDate);
+            vault.release_at = release_at;
+            msg!("Release date moved to: {}", release_at);
+        }
+
         Ok(())
     }
 }
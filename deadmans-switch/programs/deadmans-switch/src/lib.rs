@@ -5,12 +5,14 @@
 
 mod constants;
 mod errors;
+mod events;
 mod instructions;
 mod state;
 
 use anchor_lang::prelude::*;
 pub use constants::*;
 pub use errors::*;
+pub use events::*;
 use instructions::*;
 pub use state::*;
 
@@ -42,7 +44,7 @@ pub mod deadmans_switch {
 
     /// Set or clear the delegate wallet that can ping on owner's behalf.
     pub fn set_delegate(ctx: Context<SetDelegate>, new_delegate: Option<Pubkey>) -> Result<()> {
-        ctx.accounts.handler(new_delegate)
+        ctx.accounts.handler(ctx.remaining_accounts, new_delegate)
     }
 
     /// Trigger the release of vault contents if the timer has expired.
@@ -55,19 +57,29 @@ pub mod deadmans_switch {
         ctx.accounts.handler(amount)
     }
 
-    /// Update vault settings (recipient and/or interval).
+    /// Update vault settings (recipient, interval, name and/or release date).
+    /// Allocations are edited with `set_allocations`.
     pub fn update_vault(
         ctx: Context<UpdateVault>,
         new_recipient: Option<Pubkey>,
         new_time_interval: Option<i64>,
         new_name: Option<String>,
+        new_max_snooze: Option<i64>,
+        new_release_at: Option<i64>,
     ) -> Result<()> {
-        ctx.accounts.handler(new_recipient, new_time_interval, new_name)
+        ctx.accounts.handler(
+            ctx.remaining_accounts,
+            new_recipient,
+            new_time_interval,
+            new_name,
+            new_max_snooze,
+            new_release_at,
+        )
     }
 
     /// Close the vault and reclaim rent back to owner.
     pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
-        ctx.accounts.handler()
+        ctx.accounts.handler(ctx.remaining_accounts)
     }
 
     /// Claim the vault contents and close it.
@@ -89,4 +101,348 @@ pub mod deadmans_switch {
     pub fn claim_tokens(ctx: Context<ClaimTokens>) -> Result<()> {
         ctx.accounts.handler()
     }
+
+    /// Create an estate whose shared heartbeat linked vaults follow.
+    pub fn initialize_estate(ctx: Context<InitializeEstate>, time_interval: i64) -> Result<()> {
+        ctx.accounts.handler(time_interval, ctx.bumps.estate)
+    }
+
+    /// Ping (check-in) an estate to reset the timer of all linked vaults.
+    pub fn ping_estate(ctx: Context<PingEstate>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Link a vault to the owner's estate.
+    pub fn link_estate(ctx: Context<LinkEstate>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Unlink a vault from the owner's estate.
+    pub fn unlink_estate(ctx: Context<UnlinkEstate>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Configure joint-vault co-owners, survivor policy and admin threshold.
+    pub fn set_co_owners(
+        ctx: Context<SetCoOwners>,
+        co_owners: Vec<Pubkey>,
+        survivor_policy: SurvivorPolicy,
+        admin_threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, co_owners, survivor_policy, admin_threshold)
+    }
+
+    /// Configure the owner-authority set and admin approval threshold.
+    pub fn set_owner_authorities(
+        ctx: Context<SetOwnerAuthorities>,
+        authorities: Vec<Pubkey>,
+        admin_threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, authorities, admin_threshold)
+    }
+
+    /// Propose an administrative action for other admin keys to approve.
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        nonce: u64,
+        action: AdminAction,
+        args_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.handler(nonce, action, args_hash, ctx.bumps.proposal)
+    }
+
+    /// Approve a pending administrative proposal.
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Close a proposal and reclaim rent to the proposer.
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Register guardians that can recover a lost owner key.
+    pub fn set_recovery_guardians(
+        ctx: Context<SetRecoveryGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        timelock: i64,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, guardians, threshold, timelock, ctx.bumps.recovery)
+    }
+
+    /// Propose rotating the owner key (guardian only).
+    pub fn propose_recovery(ctx: Context<ProposeRecovery>, new_owner: Pubkey) -> Result<()> {
+        ctx.accounts.handler(new_owner)
+    }
+
+    /// Approve the pending recovery (guardian only).
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Cancel the pending recovery (current owner only).
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Rotate the owner key after guardian approval and timelock.
+    pub fn recover_owner(ctx: Context<RecoverOwner>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Hold the deadline until a specific timestamp (vacation mode).
+    pub fn snooze(ctx: Context<Snooze>, until: i64) -> Result<()> {
+        ctx.accounts.handler(until)
+    }
+
+    /// Switch to fixed-date (time capsule) or hybrid release.
+    pub fn set_release_mode(
+        ctx: Context<SetReleaseMode>,
+        release_mode: ReleaseMode,
+        release_at: i64,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, release_mode, release_at)
+    }
+
+    /// Set the recipient's claim-not-before date and optional minor guardian.
+    pub fn set_claim_restrictions(
+        ctx: Context<SetClaimRestrictions>,
+        claim_not_before: i64,
+        minor_guardian: Option<Pubkey>,
+        guardian_destination: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, claim_not_before, minor_guardian, guardian_destination)
+    }
+
+    /// Claim locked SOL on a minor's behalf into the restricted destination.
+    pub fn guardian_claim_sol(ctx: Context<GuardianClaimSol>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Claim locked SPL tokens on a minor's behalf into the restricted destination.
+    pub fn guardian_claim_tokens(ctx: Context<GuardianClaimTokens>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Configure fallback recipients and the claim deadline after release.
+    pub fn set_fallback_recipients(
+        ctx: Context<SetFallbackRecipients>,
+        fallback_recipients: Vec<Pubkey>,
+        claim_deadline: i64,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, fallback_recipients, claim_deadline)
+    }
+
+    /// Pass unclaimed assets to the next fallback recipient (permissionless).
+    pub fn pass_claim_rights(ctx: Context<PassClaimRights>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Configure staged inheritance tiers.
+    pub fn set_escalation_tiers(ctx: Context<SetEscalationTiers>, tiers: Vec<TierConfig>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, tiers, ctx.bumps.escalation_plan)
+    }
+
+    /// Claim the SOL slice of a released escalation tier.
+    pub fn claim_tier_sol(ctx: Context<ClaimTierSol>, tier_index: u8) -> Result<()> {
+        ctx.accounts.handler(tier_index)
+    }
+
+    /// Claim the SPL token slice of a released escalation tier.
+    pub fn claim_tier_tokens(ctx: Context<ClaimTierTokens>, tier_index: u8) -> Result<()> {
+        ctx.accounts.handler(tier_index)
+    }
+
+    /// Cascade locked SOL into a recipient vault (permissionless).
+    pub fn cascade_claim_sol(ctx: Context<CascadeClaimSol>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts)
+    }
+
+    /// Cascade locked SPL tokens into a recipient vault (permissionless).
+    pub fn cascade_claim_tokens(ctx: Context<CascadeClaimTokens>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts)
+    }
+
+    /// Take over a released vault as its new owner (recipient only).
+    pub fn succeed(
+        ctx: Context<Succeed>,
+        new_recipient: Pubkey,
+        ipfs_cid: String,
+        encrypted_key: String,
+    ) -> Result<()> {
+        ctx.accounts.handler(new_recipient, ipfs_cid, encrypted_key)
+    }
+
+    /// Release the vault immediately (owner only), optionally refunding the bounty.
+    pub fn release_now(ctx: Context<ReleaseNow>, refund_bounty: bool) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, refund_bounty)
+    }
+
+    /// Irrevocably commit the vault to its recipients (one-way).
+    pub fn make_irrevocable(ctx: Context<MakeIrrevocable>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts)
+    }
+
+    /// Choose whether expiry releases the vault or destroys its contents.
+    pub fn set_release_action(ctx: Context<SetReleaseAction>, release_action: ReleaseAction) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, release_action)
+    }
+
+    /// Relayed check-in authorized by the owner's ed25519 signature.
+    pub fn ping_with_signature(ctx: Context<PingWithSignature>, nonce: u64, expiry: i64) -> Result<()> {
+        ctx.accounts.handler(nonce, expiry)
+    }
+
+    /// Ping by revealing the next preimage of the vault's hash chain.
+    pub fn ping_with_preimage(ctx: Context<Ping>, preimage: [u8; 32]) -> Result<()> {
+        ctx.accounts.handler_with_preimage(preimage)
+    }
+
+    /// Commit a new proof-of-life hash chain, or clear it (owner only).
+    pub fn commit_hash_chain(
+        ctx: Context<CommitHashChain>,
+        head: Option<[u8; 32]>,
+        length: u32,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, head, length)
+    }
+
+    /// Register programs whose CPIs count as the owner's check-in.
+    pub fn set_liveness_adapters(ctx: Context<SetLivenessAdapters>, adapters: Vec<AdapterConfig>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, adapters)
+    }
+
+    /// Check in on the owner's behalf (CPI from a registered liveness adapter, co-signed by the owner or delegate).
+    pub fn ping_via_adapter(ctx: Context<PingViaAdapter>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Configure the expiry warning window and notification bounty.
+    pub fn set_expiry_warning(
+        ctx: Context<SetExpiryWarning>,
+        warning_window: i64,
+        notification_bounty: u64,
+        deposit: u64,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, warning_window, notification_bounty, deposit)
+    }
+
+    /// Announce that a vault is about to expire (permissionless).
+    pub fn announce_expiring(ctx: Context<AnnounceExpiring>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Configure the lamport and memo notice sent to the recipient on release.
+    pub fn set_release_notice(ctx: Context<SetReleaseNotice>, lamports: u64, memo: String) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, lamports, memo)
+    }
+
+    /// Lock an NFT (standard or programmable) into the vault.
+    pub fn lock_nft(ctx: Context<LockNft>) -> Result<()> {
+        ctx.accounts.handler(ctx.bumps.nft_record)
+    }
+
+    /// Claim one locked NFT from a released vault.
+    pub fn claim_nft(ctx: Context<ClaimNft>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Place a native stake account under the vault's staker and withdrawer authority.
+    pub fn lock_stake_account(ctx: Context<LockStakeAccount>) -> Result<()> {
+        ctx.accounts.handler(ctx.bumps.stake_record)
+    }
+
+    /// Claim a stake account's authorities from a released vault.
+    pub fn claim_stake_account(ctx: Context<ClaimStakeAccount>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Return a stake account's authorities to the owner while the vault is active.
+    pub fn reclaim_stake_account(ctx: Context<ReclaimStakeAccount>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts)
+    }
+
+    /// Delegate locked SOL from a native stake account controlled by the vault.
+    pub fn stake_locked_sol(ctx: Context<StakeLockedSol>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, ctx.bumps.auto_stake, ctx.bumps.stake_account)
+    }
+
+    /// Deposit locked SOL into an SPL stake pool; the LST becomes the vault's locked tokens.
+    pub fn stake_locked_sol_in_pool(ctx: Context<StakeLockedSolInPool>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, ctx.bumps.auto_stake, ctx.bumps.sol_escrow)
+    }
+
+    /// Move a mint or freeze authority into the vault's custody.
+    pub fn deposit_mint_authority(ctx: Context<DepositMintAuthority>, kind: CustodiedAuthority) -> Result<()> {
+        ctx.accounts.handler(kind, ctx.bumps.authority_record)
+    }
+
+    /// Move a program's upgrade authority into the vault's custody.
+    pub fn deposit_upgrade_authority(ctx: Context<DepositUpgradeAuthority>) -> Result<()> {
+        ctx.accounts.handler(ctx.bumps.authority_record)
+    }
+
+    /// Claim a custodied authority from a released vault.
+    pub fn claim_authority(ctx: Context<ClaimAuthority>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Return a custodied authority to the owner while the vault is active.
+    pub fn reclaim_authority(ctx: Context<ReclaimAuthority>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts)
+    }
+
+    /// Store instructions the vault signs after release (whitelisted programs only).
+    pub fn set_legacy_instructions(
+        ctx: Context<SetLegacyInstructions>,
+        steps: Vec<LegacyStepConfig>,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, steps, ctx.bumps.legacy_plan)
+    }
+
+    /// Execute the next stored legacy instruction of a released vault.
+    pub fn execute_legacy_step<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteLegacyStep<'info>>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts)
+    }
+
+    /// Route locked assets to their own recipients (e.g. USDC to one heir, SOL to another).
+    pub fn set_allocations(ctx: Context<SetAllocations>, assets: Vec<AssetAllocation>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, assets, ctx.bumps.allocation_table)
+    }
+
+    /// Skip a legacy step that cannot be executed (anyone, after a delay).
+    pub fn skip_legacy_step(ctx: Context<SkipLegacyStep>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Return a locked NFT to the owner while the vault is active.
+    pub fn reclaim_nft(ctx: Context<ReclaimNft>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts)
+    }
+
+    /// Cascade a locked NFT into a recipient vault (permissionless).
+    pub fn cascade_claim_nft(ctx: Context<CascadeClaimNft>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, ctx.bumps.target_nft_record)
+    }
+
+    /// Cascade a locked stake account into a recipient vault (permissionless).
+    pub fn cascade_claim_stake_account(ctx: Context<CascadeClaimStakeAccount>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, ctx.bumps.target_stake_record)
+    }
+
+    /// Cascade a custodied authority into a recipient vault (permissionless).
+    pub fn cascade_claim_authority(ctx: Context<CascadeClaimAuthority>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, ctx.bumps.target_authority_record)
+    }
+
+    /// Settle a released vault's auto-staked SOL (permissionless).
+    pub fn settle_auto_stake(ctx: Context<SettleAutoStake>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    /// Unwind auto-staked SOL back into locked SOL while the vault is active.
+    pub fn unstake_locked_sol(ctx: Context<UnstakeLockedSol>) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, ctx.bumps.sol_escrow)
+    }
 }
@@ -1,12 +1,14 @@
 //! State definitions for the Deadman's Switch program.
 
 use anchor_lang::prelude::*;
-use crate::constants::*;
+use anchor_lang::solana_program::hash::hash;
+use solana_stake_interface::state::{Meta, StakeStateV2};
+use crate::{constants::*, errors::*};
 
 /// The Vault account that stores all data for a dead man's switch.
 #[account]
 pub struct Vault {
-    /// The wallet that created this vault
+    /// The wallet currently controlling this vault (rotated by social recovery)
     pub owner: Pubkey,
 
     /// The wallet that can claim when released
@@ -50,11 +52,121 @@ pub struct Vault {
 
     /// Amount of SPL tokens locked
     pub locked_tokens: u64,
+
+    /// Estate whose shared heartbeat replaces this vault's own timer (None if unlinked)
+    pub estate: Option<Pubkey>,
+
+    /// Additional owners of a joint vault, each with their own check-in
+    pub co_owners: Vec<CoOwner>,
+
+    /// How the owners' inactivity combines into a release
+    pub survivor_policy: SurvivorPolicy,
+
+    /// Approvals (owner, co-owners, authorities) required for administrative changes
+    pub admin_threshold: u8,
+
+    /// Additional keys that may approve administrative changes but cannot ping
+    pub authorities: Vec<Pubkey>,
+
+    /// The wallet that created this vault; used in the PDA seeds and never changes
+    pub creator: Pubkey,
+
+    /// Deadline set by an explicit snooze (0 if not snoozed); cleared by the next ping
+    pub snoozed_until: i64,
+
+    /// Owner-defined cap on snooze length in seconds (0 = protocol maximum)
+    pub max_snooze: i64,
+
+    /// How the release deadline is derived (heartbeat, fixed date or both)
+    pub release_mode: ReleaseMode,
+
+    /// Absolute release timestamp for fixed-date and hybrid modes
+    pub release_at: i64,
+
+    /// Earliest timestamp the recipient may claim after release (0 = no restriction)
+    pub claim_not_before: i64,
+
+    /// Guardian who may claim on a minor recipient's behalf
+    pub minor_guardian: Option<Pubkey>,
+
+    /// Restricted destination for guardian claims (e.g. a custodial trust account)
+    pub guardian_destination: Option<Pubkey>,
+
+    /// Timestamp the vault was released (0 while active)
+    pub released_at: i64,
+
+    /// Seconds a recipient has to claim before rights pass to the next fallback (0 = forever)
+    pub claim_deadline: i64,
+
+    /// Ordered fallback recipients (e.g. a second heir, then a charity or DAO)
+    pub fallback_recipients: Vec<Pubkey>,
+
+    /// Audit trail of claim rights passed to fallback recipients
+    pub custody_log: Vec<CustodyHandoff>,
+
+    /// Whether an escalation plan with staged tiers governs part of this vault
+    pub has_escalation_plan: bool,
+
+    /// Whether an allocation table routes locked assets to their own recipients
+    pub has_allocation_table: bool,
+
+    /// Whether the owner has irrevocably committed the vault to its recipients
+    pub is_irrevocable: bool,
+
+    /// What happens to the payload and locked assets when the vault expires
+    pub release_action: ReleaseAction,
+
+    /// Nonce the next signed (relayed) check-in must carry; prevents replays
+    pub ping_nonce: u64,
+
+    /// Current head of the proof-of-life hash chain (None = chain not in use)
+    pub hash_chain_head: Option<[u8; 32]>,
+
+    /// Preimages left in the committed hash chain
+    pub hash_chain_remaining: u32,
+
+    /// Programs allowed to check in on the owner's behalf via CPI
+    pub liveness_adapters: Vec<LivenessAdapter>,
+
+    /// Seconds before the deadline in which expiry may be announced (0 = disabled)
+    pub warning_window: i64,
+
+    /// Timestamp of the last expiry announcement (0 if never)
+    pub warned_at: i64,
+
+    /// Lamports paid to whoever announces the upcoming expiry
+    pub notification_bounty: u64,
+
+    /// Owner-funded lamports set aside for notification bounties
+    pub notification_pool: u64,
+
+    /// Lamports sent to the recipient as an on-chain notice on release, taken from the bounty
+    pub release_notice_lamports: u64,
+
+    /// Memo text sent to the recipient on release (empty = default notice with the vault name)
+    pub release_notice_memo: String,
+
+    /// Number of NFTs escrowed in the vault, each tracked by its own NftRecord
+    pub locked_nfts: u16,
+
+    /// Number of native stake accounts under the vault's authority, each tracked by a StakeRecord
+    pub locked_stake_accounts: u16,
+
+    /// Whether locked SOL is currently delegated through an AutoStake position
+    pub has_auto_stake: bool,
+
+    /// Number of mint, freeze and upgrade authorities in custody, each tracked by an AuthorityRecord
+    pub custodied_authorities: u16,
+
+    /// Whether a legacy plan holds stored instructions still to be executed
+    pub has_legacy_plan: bool,
+
+    /// Whether recovery guardians have been registered in a Recovery account
+    pub has_recovery: bool,
 }
 
 impl Vault {
-    /// Calculate the space needed for a Vault account.
-    /// Previous: 383 bytes + 33 (token_mint Option<Pubkey>) + 8 (locked_tokens) = 424 bytes
+    /// Calculate the space needed for a Vault account (1568 bytes).
     pub const SPACE: usize = 8 
         + 32                          // owner
         + 32                          // recipient
@@ -70,5 +182,1115 @@ impl Vault {
         + (4 + MAX_VAULT_NAME_LEN)    // name
         + 8                           // locked_lamports
         + 33                          // token_mint (Option<Pubkey>)
-        + 8;                          // locked_tokens
+        + 8                           // locked_tokens
+        + 33                          // estate (Option<Pubkey>)
+        + (4 + MAX_CO_OWNERS * CoOwner::SPACE) // co_owners
+        + 1                           // survivor_policy
+        + 1                           // admin_threshold
+        + (4 + MAX_OWNER_AUTHORITIES * 32) // authorities
+        + 32                          // creator
+        + 8                           // snoozed_until
+        + 8                           // max_snooze
+        + 1                           // release_mode
+        + 8                           // release_at
+        + 8                           // claim_not_before
+        + 33                          // minor_guardian (Option<Pubkey>)
+        + 33                          // guardian_destination (Option<Pubkey>)
+        + 8                           // released_at
+        + 8                           // claim_deadline
+        + (4 + MAX_FALLBACK_RECIPIENTS * 32) // fallback_recipients
+        + (4 + MAX_FALLBACK_RECIPIENTS * CustodyHandoff::SPACE) // custody_log
+        + 1                           // has_escalation_plan
+        + 1                           // has_allocation_table
+        + 1                           // is_irrevocable
+        + 1                           // release_action
+        + 8                           // ping_nonce
+        + 33                          // hash_chain_head (Option<[u8; 32]>)
+        + 4                           // hash_chain_remaining
+        + (4 + MAX_LIVENESS_ADAPTERS * LivenessAdapter::SPACE) // liveness_adapters
+        + 8                           // warning_window
+        + 8                           // warned_at
+        + 8                           // notification_bounty
+        + 8                           // notification_pool
+        + 8                           // release_notice_lamports
+        + (4 + MAX_NOTICE_MEMO_LEN)   // release_notice_memo
+        + 2                           // locked_nfts
+        + 2                           // locked_stake_accounts
+        + 1                           // has_auto_stake
+        + 2                           // custodied_authorities
+        + 1                           // has_legacy_plan
+        + 1;                          // has_recovery
+
+    /// Timestamp after which the owners are considered inactive.
+    /// Vaults linked to an estate follow the estate's timer for the owner;
+    /// co-owners are combined according to the survivor policy.
+    /// An active snooze holds the deadline until it ends.
+    pub fn expiry_time(&self, estate: Option<&Estate>) -> Result<i64> {
+        let (last_check_in, time_interval) = match estate {
+            Some(estate) => (estate.last_check_in, estate.time_interval),
+            None => (self.last_check_in, self.time_interval),
+        };
+
+        let mut expiry_time = last_check_in
+            .checked_add(time_interval)
+            .ok_or(VaultError::Overflow)?;
+
+        for co_owner in &self.co_owners {
+            let co_owner_expiry = co_owner
+                .last_check_in
+                .checked_add(self.time_interval)
+                .ok_or(VaultError::Overflow)?;
+
+            expiry_time = match self.survivor_policy {
+                SurvivorPolicy::All => expiry_time.max(co_owner_expiry),
+                SurvivorPolicy::Any => expiry_time.min(co_owner_expiry),
+            };
+        }
+
+        Ok(expiry_time.max(self.snoozed_until))
+    }
+
+    /// Most recent owner activity, combining co-owners by the survivor policy.
+    /// Escalation tiers measure their inactivity depth from here.
+    pub fn last_activity(&self, estate: Option<&Estate>) -> i64 {
+        let owner_check_in = estate.map_or(self.last_check_in, |e| e.last_check_in);
+
+        self.co_owners.iter().fold(owner_check_in, |acc, c| match self.survivor_policy {
+            SurvivorPolicy::All => acc.max(c.last_check_in),
+            SurvivorPolicy::Any => acc.min(c.last_check_in),
+        })
+    }
+
+    /// Timestamp after which the vault may be released, according to its release mode.
+    pub fn release_time(&self, estate: Option<&Estate>) -> Result<i64> {
+        Ok(match self.release_mode {
+            ReleaseMode::Heartbeat => self.expiry_time(estate)?,
+            ReleaseMode::FixedDate => self.release_at,
+            ReleaseMode::HybridEarliest => self.expiry_time(estate)?.min(self.release_at),
+            ReleaseMode::HybridLatest => self.expiry_time(estate)?.max(self.release_at),
+        })
+    }
+
+    /// Require that a check-in can still extend this vault.
+    pub fn require_pingable(&self) -> Result<()> {
+        require!(!self.is_released, VaultError::AlreadyReleased);
+        // Time capsules release on their date; check-ins have no effect
+        require!(
+            self.release_mode != ReleaseMode::FixedDate,
+            VaultError::ReleaseModeMismatch
+        );
+        Ok(())
+    }
+
+    /// Require that check-ins don't need a hash-chain preimage.
+    pub fn require_no_hash_chain(&self) -> Result<()> {
+        require!(self.hash_chain_head.is_none(), VaultError::PreimageRequired);
+        Ok(())
+    }
+
+    /// Require that the owner's check-ins are kept on this vault, not on a linked estate.
+    pub fn require_own_heartbeat(&self) -> Result<()> {
+        require!(self.estate.is_none(), VaultError::LinkedToEstate);
+        Ok(())
+    }
+
+    /// Reveal the next hash-chain preimage, which becomes the new chain head.
+    pub fn reveal_preimage(&mut self, preimage: &[u8; 32]) -> Result<()> {
+        let head = self.hash_chain_head.ok_or(VaultError::NoHashChain)?;
+        require!(self.hash_chain_remaining > 0, VaultError::HashChainExhausted);
+        require!(hash(preimage).to_bytes() == head, VaultError::InvalidPreimage);
+
+        self.hash_chain_head = Some(*preimage);
+        self.hash_chain_remaining -= 1;
+        Ok(())
+    }
+
+    /// End any snooze; a regular check-in restores the normal interval.
+    pub fn clear_snooze(&mut self) {
+        if self.snoozed_until != 0 {
+            msg!("Snooze until {} cleared", self.snoozed_until);
+            self.snoozed_until = 0;
+        }
+    }
+
+    /// Require that the owner has not made the vault irrevocable.
+    pub fn require_revocable(&self) -> Result<()> {
+        require!(!self.is_irrevocable, VaultError::VaultIrrevocable);
+        Ok(())
+    }
+
+    /// Whether the recipient gets an on-chain notice when the vault is released.
+    pub fn sends_release_notice(&self) -> bool {
+        self.release_notice_lamports > 0 || !self.release_notice_memo.is_empty()
+    }
+
+    /// Mark the vault released, opening the recipient's claim paths.
+    pub fn mark_released(&mut self, now: i64) {
+        self.is_released = true;
+        self.released_at = now;
+    }
+
+    /// Require that the recipient's claim-not-before date has passed.
+    pub fn require_claim_open(&self, now: i64) -> Result<()> {
+        require!(now >= self.claim_not_before, VaultError::ClaimTooEarly);
+        Ok(())
+    }
+
+    /// Timestamp after which the current recipient's claim window has lapsed.
+    /// The window opens at release (or the claim-not-before date) and restarts on each handoff.
+    pub fn claim_window_end(&self) -> Result<i64> {
+        let window_start = match self.custody_log.last() {
+            Some(handoff) => handoff.at,
+            None => self.released_at.max(self.claim_not_before),
+        };
+
+        window_start
+            .checked_add(self.claim_deadline)
+            .ok_or_else(|| VaultError::Overflow.into())
+    }
+
+    /// Longest snooze, in seconds from now, this vault allows.
+    pub fn snooze_limit(&self) -> i64 {
+        if self.max_snooze > 0 {
+            self.max_snooze.min(MAX_SNOOZE_DURATION)
+        } else {
+            MAX_SNOOZE_DURATION
+        }
+    }
+
+    /// Whether the key is the owner or one of the co-owners.
+    pub fn is_owner_key(&self, key: &Pubkey) -> bool {
+        *key == self.owner || self.co_owners.iter().any(|c| c.key == *key)
+    }
+
+    /// Whether the key may approve administrative changes.
+    pub fn is_admin_key(&self, key: &Pubkey) -> bool {
+        self.is_owner_key(key) || self.authorities.contains(key)
+    }
+
+    /// Number of distinct keys that may approve administrative changes.
+    pub fn admin_key_count(&self) -> usize {
+        let mut keys: Vec<&Pubkey> = Vec::new();
+        for key in std::iter::once(&self.owner)
+            .chain(self.co_owners.iter().map(|c| &c.key))
+            .chain(self.authorities.iter())
+        {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        keys.len()
+    }
+
+    /// Require `admin_threshold` distinct approvals for an administrative action.
+    /// Approvals come from the owner signing the instruction, admin keys co-signing
+    /// via remaining accounts, and an optional proposal approved for this exact action.
+    /// The proposal must already be checked to belong to this vault;
+    /// one that contributes approvals is marked executed.
+    pub fn require_admin_approval(
+        &self,
+        owner: &Pubkey,
+        co_signers: &[AccountInfo],
+        proposal: Option<&mut Account<Proposal>>,
+        action: AdminAction,
+        args: &impl AnchorSerialize,
+    ) -> Result<()> {
+        let mut approved: Vec<Pubkey> = Vec::new();
+        let mut approve = |key: &Pubkey| {
+            if self.is_admin_key(key) && !approved.contains(key) {
+                approved.push(*key);
+            }
+        };
+
+        approve(owner);
+        co_signers.iter().filter(|a| a.is_signer).for_each(|a| approve(a.key));
+
+        if let Some(proposal) = proposal {
+            require!(!proposal.executed, VaultError::ProposalExecuted);
+            require!(
+                proposal.action == action && proposal.args_hash == Proposal::hash_args(args)?,
+                VaultError::ProposalMismatch
+            );
+
+            proposal.approvals.iter().for_each(&mut approve);
+            proposal.executed = true;
+        }
+
+        require!(
+            approved.len() >= self.admin_threshold as usize,
+            VaultError::InsufficientSignatures
+        );
+
+        Ok(())
+    }
+
+    /// Amount of an asset (None = native SOL) the claimant may take out of `remaining`.
+    /// Assets listed in the allocation table pay out share by share; any other asset
+    /// goes to the recipient in full. The table must already be checked to belong to this vault.
+    pub fn claimable_amount(
+        &self,
+        table: Option<&mut Account<AllocationTable>>,
+        mint: Option<Pubkey>,
+        claimant: &Pubkey,
+        remaining: u64,
+    ) -> Result<u64> {
+        if self.has_allocation_table {
+            let table = table.ok_or(VaultError::AllocationTableRequired)?;
+            if let Some(asset) = table.assets.iter_mut().find(|a| a.mint == mint) {
+                return asset.claim_share(claimant, remaining);
+            }
+        }
+
+        require_keys_eq!(*claimant, self.recipient, VaultError::NotRecipient);
+        Ok(remaining)
+    }
+
+    /// Reject a claimant with nothing to take of an asset (None = native SOL), without
+    /// consuming their allocation share the way `claimable_amount` does.
+    pub fn require_claimant(
+        &self,
+        table: Option<&AllocationTable>,
+        mint: Option<Pubkey>,
+        claimant: &Pubkey,
+    ) -> Result<()> {
+        if self.has_allocation_table {
+            let table = table.ok_or(VaultError::AllocationTableRequired)?;
+            if let Some(asset) = table.assets.iter().find(|a| a.mint == mint) {
+                require!(
+                    asset.shares.iter().any(|s| s.recipient == *claimant),
+                    VaultError::NotRecipient
+                );
+                return Ok(());
+            }
+        }
+
+        require_keys_eq!(*claimant, self.recipient, VaultError::NotRecipient);
+        Ok(())
+    }
+
+    /// Walk the recipient chain onward from this vault (the cascade target) and reject
+    /// any path back to `source`. `chain` must list each further vault in order, followed
+    /// by the first recipient that is not a vault, which ends the walk.
+    pub fn require_acyclic_cascade(
+        &self,
+        source: Pubkey,
+        target: Pubkey,
+        chain: &[AccountInfo],
+    ) -> Result<()> {
+        let mut seen = vec![source, target];
+        let mut next = self.recipient;
+        let mut accounts = chain.iter();
+
+        loop {
+            require!(!seen.contains(&next), VaultError::CascadeCycle);
+            require!(seen.len() <= MAX_CASCADE_DEPTH, VaultError::CascadeTooDeep);
+
+            let account = accounts.next().ok_or(VaultError::CascadeChainIncomplete)?;
+            require_keys_eq!(*account.key, next, VaultError::CascadeChainIncomplete);
+            if account.owner != &crate::ID {
+                return Ok(());
+            }
+
+            let data = account.try_borrow_data()?;
+            match Vault::try_deserialize(&mut &data[..]) {
+                Ok(vault) => {
+                    seen.push(next);
+                    next = vault.recipient;
+                }
+                Err(_) => return Ok(()),
+            }
+        }
+    }
+
+    /// Resolve the estate that governs this vault's heartbeat.
+    /// Linked vaults must be given their estate; unlinked vaults ignore it.
+    pub fn linked_estate<'a>(&self, estate: Option<&'a Account<Estate>>) -> Result<Option<&'a Estate>> {
+        match self.estate {
+            Some(linked) => {
+                let estate = estate.ok_or(VaultError::EstateMismatch)?;
+                require_keys_eq!(estate.key(), linked, VaultError::EstateMismatch);
+                Ok(Some(estate))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// A co-owner of a joint vault and their individual heartbeat.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CoOwner {
+    /// The co-owner's wallet
+    pub key: Pubkey,
+
+    /// Timestamp of this co-owner's last check-in
+    pub last_check_in: i64,
+}
+
+impl CoOwner {
+    pub const SPACE: usize = 32 + 8;
+}
+
+/// A program whose CPIs count as the owner's check-in (e.g. a DAO governance program).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LivenessAdapter {
+    /// The adapter program that may CPI into ping_via_adapter
+    pub program_id: Pubkey,
+
+    /// Minimum seconds between check-ins from this adapter
+    pub min_interval: i64,
+
+    /// Timestamp of this adapter's last check-in
+    pub last_ping: i64,
+}
+
+impl LivenessAdapter {
+    pub const SPACE: usize = 32 + 8 + 8;
+}
+
+/// Owner-supplied configuration for one liveness adapter.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AdapterConfig {
+    /// The adapter program that may CPI into ping_via_adapter
+    pub program_id: Pubkey,
+
+    /// Minimum seconds between check-ins from this adapter
+    pub min_interval: i64,
+}
+
+impl From<AdapterConfig> for LivenessAdapter {
+    fn from(config: AdapterConfig) -> Self {
+        Self {
+            program_id: config.program_id,
+            min_interval: config.min_interval,
+            last_ping: 0,
+        }
+    }
+}
+
+/// A recorded transfer of claim rights to a fallback recipient.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CustodyHandoff {
+    /// Recipient whose claim window lapsed
+    pub from: Pubkey,
+
+    /// Fallback recipient who now holds claim rights
+    pub to: Pubkey,
+
+    /// Timestamp of the handoff
+    pub at: i64,
+}
+
+impl CustodyHandoff {
+    pub const SPACE: usize = 32 + 32 + 8;
+}
+
+/// How the inactivity of joint owners combines into a release.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SurvivorPolicy {
+    /// Release only once every owner has stopped checking in
+    All,
+
+    /// Release as soon as any owner stops checking in
+    Any,
+}
+
+/// How a vault's release deadline is derived.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReleaseMode {
+    /// Release after the owners stop checking in
+    Heartbeat,
+
+    /// Release at `release_at` regardless of pings (time capsule)
+    FixedDate,
+
+    /// Release at whichever of the heartbeat deadline and `release_at` comes first
+    HybridEarliest,
+
+    /// Release at whichever of the heartbeat deadline and `release_at` comes last
+    HybridLatest,
+}
+
+/// What expiry does to a vault's payload and locked assets.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReleaseAction {
+    /// Release the payload and assets to the recipient
+    Release,
+
+    /// Wipe the payload, burn locked tokens and return locked SOL to the owner
+    DestroyBurnTokens,
+
+    /// Wipe the payload and return locked tokens and SOL to the owner
+    DestroyReturnTokens,
+}
+
+/// Shared heartbeat for every vault an owner links to it.
+/// A single `ping_estate` keeps all linked vaults alive.
+#[account]
+pub struct Estate {
+    /// The wallet that owns this estate
+    pub owner: Pubkey,
+
+    /// Check-in interval in seconds
+    pub time_interval: i64,
+
+    /// Timestamp of last check-in
+    pub last_check_in: i64,
+
+    /// Number of vaults currently linked to this estate
+    pub linked_vaults: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Estate {
+    /// Calculate the space needed for an Estate account.
+    pub const SPACE: usize = 8
+        + 32                          // owner
+        + 8                           // time_interval
+        + 8                           // last_check_in
+        + 4                           // linked_vaults
+        + 1;                          // bump
+}
+
+/// Administrative actions that require the vault's approval threshold.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminAction {
+    UpdateVault,
+    CloseVault,
+    SetDelegate,
+    SetCoOwners,
+    SetOwnerAuthorities,
+    SetRecoveryGuardians,
+    SetReleaseMode,
+    SetClaimRestrictions,
+    SetFallbackRecipients,
+    SetEscalationTiers,
+    ReleaseNow,
+    MakeIrrevocable,
+    SetReleaseAction,
+    CommitHashChain,
+    SetLivenessAdapters,
+    SetExpiryWarning,
+    SetReleaseNotice,
+    ReclaimStakeAccount,
+    StakeLockedSol,
+    ReclaimAuthority,
+    SetLegacyInstructions,
+    SetAllocations,
+    ReclaimNft,
+    UnstakeLockedSol,
+}
+
+/// A pending administrative action that admin keys approve one by one.
+/// Executing the matching instruction consumes the proposal's approvals.
+#[account]
+pub struct Proposal {
+    /// The vault this proposal administers
+    pub vault: Pubkey,
+
+    /// The admin key that created the proposal and paid its rent
+    pub proposer: Pubkey,
+
+    /// Which administrative instruction this proposal authorizes
+    pub action: AdminAction,
+
+    /// SHA-256 of the Borsh-serialized instruction arguments
+    pub args_hash: [u8; 32],
+
+    /// Admin keys that have approved
+    pub approvals: Vec<Pubkey>,
+
+    /// Whether the approved action has been executed
+    pub executed: bool,
+
+    /// Unique nonce for this proposal
+    pub nonce: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Calculate the space needed for a Proposal account.
+    pub const SPACE: usize = 8
+        + 32                          // vault
+        + 32                          // proposer
+        + 1                           // action
+        + 32                          // args_hash
+        + (4 + MAX_PROPOSAL_APPROVALS * 32) // approvals
+        + 1                           // executed
+        + 8                           // nonce
+        + 1;                          // bump
+
+    /// Hash instruction arguments the same way clients do when proposing.
+    pub fn hash_args(args: &impl AnchorSerialize) -> Result<[u8; 32]> {
+        Ok(hash(&args.try_to_vec()?).to_bytes())
+    }
+}
+
+/// Guardians able to rotate a lost owner key, stored next to the vault.
+#[account]
+pub struct Recovery {
+    /// The vault this recovery configuration protects
+    pub vault: Pubkey,
+
+    /// The owner who registered these guardians; stale once the owner changes otherwise
+    pub owner: Pubkey,
+
+    /// Registered recovery guardians
+    pub guardians: Vec<Pubkey>,
+
+    /// Guardian approvals required to recover the owner key
+    pub threshold: u8,
+
+    /// Seconds the current owner has to cancel a recovery before it can execute
+    pub timelock: i64,
+
+    /// The recovery currently in progress (None if idle)
+    pub pending: Option<PendingRecovery>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Recovery {
+    /// Calculate the space needed for a Recovery account.
+    pub const SPACE: usize = 8
+        + 32                          // vault
+        + 32                          // owner
+        + (4 + MAX_GUARDIANS * 32)    // guardians
+        + 1                           // threshold
+        + 8                           // timelock
+        + (1 + PendingRecovery::SPACE) // pending
+        + 1;                          // bump
+}
+
+/// A proposed owner rotation awaiting guardian approvals and the timelock.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PendingRecovery {
+    /// The key that will become the vault owner
+    pub new_owner: Pubkey,
+
+    /// Guardians that have approved
+    pub approvals: Vec<Pubkey>,
+
+    /// Timestamp the recovery was proposed
+    pub initiated_at: i64,
+}
+
+impl PendingRecovery {
+    pub const SPACE: usize = 32 + (4 + MAX_GUARDIANS * 32) + 8;
+}
+
+/// Staged inheritance: tiers released one at a time at increasing inactivity depths.
+#[account]
+pub struct EscalationPlan {
+    /// The vault this plan escalates
+    pub vault: Pubkey,
+
+    /// Tiers ordered by increasing inactivity threshold
+    pub tiers: Vec<EscalationTier>,
+
+    /// Index of the next tier to release
+    pub next_tier: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl EscalationPlan {
+    /// Calculate the space needed for an EscalationPlan account.
+    pub const SPACE: usize = 8
+        + 32                          // vault
+        + (4 + MAX_ESCALATION_TIERS * EscalationTier::SPACE) // tiers
+        + 1                           // next_tier
+        + 1;                          // bump
+
+    /// Index of the next tier if its inactivity threshold has passed.
+    /// An active snooze holds every tier until it ends.
+    pub fn next_due_tier(&self, last_activity: i64, snoozed_until: i64, now: i64) -> Result<Option<usize>> {
+        let index = self.next_tier as usize;
+        let Some(tier) = self.tiers.get(index) else {
+            return Ok(None);
+        };
+
+        let due_at = last_activity
+            .checked_add(tier.inactivity)
+            .ok_or(VaultError::Overflow)?
+            .max(snoozed_until);
+
+        Ok((now > due_at).then_some(index))
+    }
+
+    /// Total lamports, tokens and bounty reserved by unclaimed tiers.
+    pub fn reserved(&self) -> Result<(u64, u64, u64)> {
+        self.tiers.iter().try_fold((0u64, 0u64, 0u64), |(l, t, b), tier| {
+            Ok((
+                l.checked_add(tier.lamports).ok_or(VaultError::Overflow)?,
+                t.checked_add(tier.tokens).ok_or(VaultError::Overflow)?,
+                b.checked_add(tier.bounty_lamports).ok_or(VaultError::Overflow)?,
+            ))
+        })
+    }
+}
+
+/// Owner-supplied configuration for one escalation tier.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TierConfig {
+    /// Seconds of owner inactivity before this tier releases
+    pub inactivity: i64,
+
+    /// The wallet that can claim this tier
+    pub beneficiary: Pubkey,
+
+    /// Payload key for this tier's beneficiary (base64-encoded encrypted AES key)
+    pub encrypted_key: String,
+
+    /// Slice of locked SOL (lamports) assigned to this tier
+    pub lamports: u64,
+
+    /// Slice of locked SPL tokens assigned to this tier
+    pub tokens: u64,
+
+    /// Bounty paid to the hunter who releases this tier
+    pub bounty_lamports: u64,
+
+    /// Earliest timestamp this tier's beneficiary may claim after release (0 = no restriction)
+    pub claim_not_before: i64,
+}
+
+/// One stage of an escalation plan.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EscalationTier {
+    /// Seconds of owner inactivity before this tier releases
+    pub inactivity: i64,
+
+    /// The wallet that can claim this tier
+    pub beneficiary: Pubkey,
+
+    /// Payload key for this tier's beneficiary (base64-encoded encrypted AES key)
+    pub encrypted_key: String,
+
+    /// Unclaimed lamports assigned to this tier
+    pub lamports: u64,
+
+    /// Unclaimed SPL tokens assigned to this tier
+    pub tokens: u64,
+
+    /// Bounty reserved for the hunter who releases this tier (0 once paid)
+    pub bounty_lamports: u64,
+
+    /// Timestamp the tier was released (0 while pending)
+    pub released_at: i64,
+
+    /// Earliest timestamp this tier's beneficiary may claim after release (0 = no restriction)
+    pub claim_not_before: i64,
+}
+
+impl EscalationTier {
+    pub const SPACE: usize = 8 + 32 + (4 + MAX_ENCRYPTED_KEY_LEN) + 8 + 8 + 8 + 8 + 8;
+
+    /// Require that the tier is released and its beneficiary's claim-not-before date has passed.
+    pub fn require_claim_open(&self, now: i64) -> Result<()> {
+        require!(self.released_at > 0, VaultError::TierNotReleased);
+        require!(now >= self.claim_not_before, VaultError::ClaimTooEarly);
+        Ok(())
+    }
+}
+
+impl From<TierConfig> for EscalationTier {
+    fn from(config: TierConfig) -> Self {
+        Self {
+            inactivity: config.inactivity,
+            beneficiary: config.beneficiary,
+            encrypted_key: config.encrypted_key,
+            lamports: config.lamports,
+            tokens: config.tokens,
+            bounty_lamports: config.bounty_lamports,
+            released_at: 0,
+            claim_not_before: config.claim_not_before,
+        }
+    }
+}
+
+/// Per-asset beneficiary routing, e.g. USDC to one heir and the SOL to another.
+#[account]
+pub struct AllocationTable {
+    /// The vault whose assets this table routes
+    pub vault: Pubkey,
+
+    /// Routed assets; assets not listed go to the vault recipient
+    pub assets: Vec<AssetAllocation>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AllocationTable {
+    /// Calculate the space needed for an AllocationTable account.
+    pub const SPACE: usize = 8
+        + 32                          // vault
+        + (4 + MAX_ALLOCATED_ASSETS * AssetAllocation::SPACE) // assets
+        + 1;                          // bump
+
+    /// Validate owner-supplied allocations.
+    pub fn validate(assets: &[AssetAllocation]) -> Result<()> {
+        require!(assets.len() <= MAX_ALLOCATED_ASSETS, VaultError::TooManyAllocations);
+
+        for (i, asset) in assets.iter().enumerate() {
+            require!(
+                assets[..i].iter().all(|a| a.mint != asset.mint),
+                VaultError::InvalidAllocation
+            );
+            require!(
+                asset.shares.len() <= MAX_ALLOCATION_SHARES,
+                VaultError::TooManyAllocations
+            );
+
+            let mut total: u16 = 0;
+            for (j, share) in asset.shares.iter().enumerate() {
+                require!(share.bps > 0, VaultError::InvalidAllocation);
+                require!(
+                    asset.shares[..j].iter().all(|s| s.recipient != share.recipient),
+                    VaultError::InvalidAllocation
+                );
+                total = total.checked_add(share.bps).ok_or(VaultError::InvalidAllocation)?;
+            }
+            require!(total == TOTAL_BASIS_POINTS, VaultError::InvalidAllocation);
+        }
+
+        Ok(())
+    }
+}
+
+/// How one asset is split between recipients.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AssetAllocation {
+    /// Token mint of the asset (None = native SOL)
+    pub mint: Option<Pubkey>,
+
+    /// Unclaimed shares; each is removed once its recipient claims
+    pub shares: Vec<AllocationShare>,
+}
+
+impl AssetAllocation {
+    pub const SPACE: usize = 33 + (4 + MAX_ALLOCATION_SHARES * AllocationShare::SPACE);
+
+    /// Claim the recipient's share of what is left of the asset.
+    /// Shares are paid relative to the unclaimed total, so the last claimant takes the remainder.
+    pub fn claim_share(&mut self, recipient: &Pubkey, remaining: u64) -> Result<u64> {
+        let index = self
+            .shares
+            .iter()
+            .position(|s| s.recipient == *recipient)
+            .ok_or(VaultError::NotRecipient)?;
+        let unclaimed_bps: u64 = self.shares.iter().map(|s| s.bps as u64).sum();
+
+        let amount = (remaining as u128)
+            .checked_mul(self.shares[index].bps as u128)
+            .and_then(|a| a.checked_div(unclaimed_bps as u128))
+            .ok_or(VaultError::Overflow)? as u64;
+
+        self.shares.remove(index);
+        Ok(amount)
+    }
+
+    /// Fold every unclaimed share into a single share for `recipient`.
+    pub fn hand_off(&mut self, recipient: Pubkey) {
+        let bps = self.shares.iter().map(|s| s.bps).sum();
+        if bps > 0 {
+            self.shares = vec![AllocationShare { recipient, bps }];
+        }
+    }
+}
+
+/// One recipient's portion of an allocated asset.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AllocationShare {
+    /// The wallet that can claim this share
+    pub recipient: Pubkey,
+
+    /// Portion of the asset in basis points
+    pub bps: u16,
+}
+
+impl AllocationShare {
+    pub const SPACE: usize = 32 + 2;
+}
+
+/// An NFT escrowed in a vault. One record per mint so NFTs can be claimed one at a time.
+#[account]
+pub struct NftRecord {
+    /// The vault holding the NFT
+    pub vault: Pubkey,
+
+    /// The NFT mint
+    pub mint: Pubkey,
+
+    /// Whether the NFT is a Metaplex programmable NFT (moved via Token Metadata)
+    pub is_programmable: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl NftRecord {
+    /// Calculate the space needed for an NftRecord account.
+    pub const SPACE: usize = 8
+        + 32                          // vault
+        + 32                          // mint
+        + 1                           // is_programmable
+        + 1;                          // bump
+}
+
+/// A native stake account whose staker and withdrawer authorities the vault holds.
+#[account]
+pub struct StakeRecord {
+    /// The vault holding the authorities
+    pub vault: Pubkey,
+
+    /// The stake account
+    pub stake_account: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StakeRecord {
+    /// Calculate the space needed for a StakeRecord account.
+    pub const SPACE: usize = 8
+        + 32                          // vault
+        + 32                          // stake_account
+        + 1;                          // bump
+
+    /// Authorities and lockup of an initialized (or delegated) native stake account.
+    pub fn stake_meta(stake_account: &AccountInfo) -> Result<Meta> {
+        let data = stake_account.try_borrow_data()?;
+        match StakeStateV2::deserialize(&mut &data[..]) {
+            Ok(StakeStateV2::Initialized(meta)) | Ok(StakeStateV2::Stake(meta, _, _)) => Ok(meta),
+            _ => err!(VaultError::InvalidStakeAccount),
+        }
+    }
+
+    /// The custodian that must co-sign a withdrawer change, if the lockup is still in force.
+    pub fn lockup_custodian<'a, 'info>(
+        meta: &Meta,
+        custodian: Option<&'a AccountInfo<'info>>,
+        clock: &Clock,
+    ) -> Result<Option<&'a AccountInfo<'info>>> {
+        if !meta.lockup.is_in_force(clock, None) {
+            return Ok(None);
+        }
+
+        match custodian {
+            Some(custodian) if custodian.is_signer && custodian.key() == meta.lockup.custodian => {
+                Ok(Some(custodian))
+            }
+            _ => err!(VaultError::StakeLockupInForce),
+        }
+    }
+}
+
+/// How locked SOL is put to work while the vault waits.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AutoStakeMode {
+    /// Delegated from a native stake account controlled by the vault PDA
+    NativeStake,
+    /// Deposited into an SPL stake pool; the vault holds the pool's LST as its locked tokens
+    StakePool,
+}
+
+/// Locked SOL delegated on the estate's behalf, with the principal put in.
+#[account]
+pub struct AutoStake {
+    /// The vault whose SOL is staked
+    pub vault: Pubkey,
+
+    /// Native stake account or stake pool deposit
+    pub mode: AutoStakeMode,
+
+    /// The native stake account, or the stake pool deposited into
+    pub target: Pubkey,
+
+    /// Lamports put in (for native stake, including the rent reserve the owner paid)
+    pub principal: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AutoStake {
+    /// Calculate the space needed for an AutoStake account.
+    pub const SPACE: usize = 8
+        + 32                          // vault
+        + 1                           // mode
+        + 32                          // target
+        + 8                           // principal
+        + 1;                          // bump
+
+    /// Byte offset of `total_lamports` in an SPL stake pool account
+    /// (followed by `pool_token_supply`).
+    const POOL_TOTAL_LAMPORTS_OFFSET: usize = 258;
+
+    /// Lamport value of `pool_tokens` at the stake pool's current exchange rate.
+    pub fn pool_value(stake_pool: &AccountInfo, pool_tokens: u64) -> Result<u64> {
+        let data = stake_pool.try_borrow_data()?;
+        let offset = Self::POOL_TOTAL_LAMPORTS_OFFSET;
+        let read_u64 = |at: usize| -> Result<u64> {
+            let bytes = data.get(at..at + 8).ok_or(VaultError::InvalidStakePool)?;
+            Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+        };
+        let total_lamports = read_u64(offset)?;
+        let pool_token_supply = read_u64(offset + 8)?;
+
+        if pool_token_supply == 0 {
+            return Ok(0);
+        }
+
+        Ok((pool_tokens as u128 * total_lamports as u128 / pool_token_supply as u128) as u64)
+    }
+}
+
+/// Kind of authority a vault can hold in custody.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CustodiedAuthority {
+    /// SPL mint authority
+    MintTokens,
+    /// SPL freeze authority
+    FreezeAccount,
+    /// BPF upgradeable program's upgrade authority
+    ProgramUpgrade,
+}
+
+/// A mint, freeze or upgrade authority held by the vault PDA until it is handed over.
+#[account]
+pub struct AuthorityRecord {
+    /// The vault holding the authority
+    pub vault: Pubkey,
+
+    /// The mint, or the upgradeable program, the authority governs
+    pub target: Pubkey,
+
+    /// Which authority is held
+    pub kind: CustodiedAuthority,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuthorityRecord {
+    /// Calculate the space needed for an AuthorityRecord account.
+    pub const SPACE: usize = 8
+        + 32                          // vault
+        + 32                          // target
+        + 1                           // kind
+        + 1;                          // bump
+}
+
+/// Stored instructions the vault PDA signs after release, executed one at a time in order.
+#[account]
+pub struct LegacyPlan {
+    /// The vault this plan belongs to
+    pub vault: Pubkey,
+
+    /// Steps in execution order
+    pub steps: Vec<LegacyStep>,
+
+    /// Index of the next step to execute
+    pub next_step: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LegacyPlan {
+    /// Calculate the space needed for a LegacyPlan account.
+    pub const SPACE: usize = 8
+        + 32                          // vault
+        + (4 + MAX_LEGACY_STEPS * LegacyStep::SPACE) // steps
+        + 1                           // next_step
+        + 1;                          // bump
+
+    /// Check owner-supplied steps: whitelisted programs, size limits, and no signer but the vault.
+    pub fn validate(vault: &Pubkey, steps: &[LegacyStepConfig]) -> Result<()> {
+        require!(steps.len() <= MAX_LEGACY_STEPS, VaultError::TooManyLegacySteps);
+
+        for step in steps {
+            require!(
+                LEGACY_PROGRAM_WHITELIST.contains(&step.program_id),
+                VaultError::ProgramNotWhitelisted
+            );
+            require!(
+                step.accounts.len() <= MAX_LEGACY_STEP_ACCOUNTS
+                    && step.data.len() <= MAX_LEGACY_STEP_DATA_LEN,
+                VaultError::InvalidLegacyStep
+            );
+            require!(
+                step.accounts.iter().all(|meta| !meta.is_signer || meta.pubkey == *vault),
+                VaultError::InvalidLegacyStep
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Owner-supplied instruction for a legacy plan.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LegacyStepConfig {
+    /// Program the instruction calls (must be whitelisted)
+    pub program_id: Pubkey,
+
+    /// Accounts in instruction order; only the vault may be a signer
+    pub accounts: Vec<LegacyAccountMeta>,
+
+    /// Serialized instruction data
+    pub data: Vec<u8>,
+}
+
+/// One stored instruction in a legacy plan.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LegacyStep {
+    /// Program the instruction calls
+    pub program_id: Pubkey,
+
+    /// Accounts in instruction order
+    pub accounts: Vec<LegacyAccountMeta>,
+
+    /// Serialized instruction data
+    pub data: Vec<u8>,
+
+    /// Timestamp the step was executed (0 if pending, negated if it was skipped)
+    pub executed_at: i64,
+}
+
+impl LegacyStep {
+    pub const SPACE: usize = 32
+        + (4 + MAX_LEGACY_STEP_ACCOUNTS * LegacyAccountMeta::SPACE)
+        + (4 + MAX_LEGACY_STEP_DATA_LEN)
+        + 8;
+}
+
+impl From<LegacyStepConfig> for LegacyStep {
+    fn from(config: LegacyStepConfig) -> Self {
+        Self {
+            program_id: config.program_id,
+            accounts: config.accounts,
+            data: config.data,
+            executed_at: 0,
+        }
+    }
+}
+
+/// Account reference of a stored instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LegacyAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl LegacyAccountMeta {
+    pub const SPACE: usize = 32 + 1 + 1;
 }
@@ -0,0 +1,92 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+describe("Vault Per-Asset Allocation Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    it("setAllocations - Routes SOL through an allocation table", async () => {
+        const vaultSeed = new BN(1500001);
+        const recipient = Keypair.generate().publicKey;
+        const heirA = Keypair.generate().publicKey;
+        const heirB = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [allocationPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("allocation"), vaultPda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Split Estate", new BN(1000000)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Shares must add up to 100%
+        try {
+            await program.methods
+                .setAllocations([
+                    { mint: null, shares: [{ recipient: heirA, bps: 6000 }, { recipient: heirB, bps: 3000 }] },
+                ])
+                .accounts({
+                    vault: vaultPda,
+                    owner: payer.publicKey,
+                    allocationTable: allocationPda,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            expect.fail("Should have thrown InvalidAllocation error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("InvalidAllocation");
+        }
+
+        await program.methods
+            .setAllocations([
+                { mint: null, shares: [{ recipient: heirA, bps: 6000 }, { recipient: heirB, bps: 4000 }] },
+            ])
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                allocationTable: allocationPda,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.hasAllocationTable).to.be.true;
+
+        const table = await program.account.allocationTable.fetch(allocationPda);
+        expect(table.assets.length).to.equal(1);
+        expect(table.assets[0].mint).to.be.null;
+        expect(table.assets[0].shares.length).to.equal(2);
+    });
+});
@@ -0,0 +1,253 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import {
+    PublicKey,
+    SystemProgram,
+    Keypair,
+    Transaction,
+    TransactionInstruction,
+} from "@solana/web3.js";
+import { BN, utils } from "@coral-xyz/anchor";
+
+const TOKEN_PROGRAM_ID = utils.token.TOKEN_PROGRAM_ID;
+
+describe("Vault Authority Custody Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    async function initializeVault(vaultSeed: BN, recipient: PublicKey, name: string): Promise<PublicKey> {
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), name, new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        return vaultPda;
+    }
+
+    // Create a mint whose mint authority is the payer
+    async function createMint(): Promise<PublicKey> {
+        const mint = Keypair.generate();
+        const rent = await banksClient.getRent();
+
+        // InitializeMint2: decimals 6, payer as mint authority, no freeze authority
+        const data = Buffer.concat([Buffer.from([20, 6]), payer.publicKey.toBuffer(), Buffer.from([0])]);
+
+        const tx = new Transaction().add(
+            SystemProgram.createAccount({
+                fromPubkey: payer.publicKey,
+                newAccountPubkey: mint.publicKey,
+                lamports: Number(rent.minimumBalance(BigInt(82))),
+                space: 82,
+                programId: TOKEN_PROGRAM_ID,
+            }),
+            new TransactionInstruction({
+                programId: TOKEN_PROGRAM_ID,
+                keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+                data,
+            })
+        );
+        await provider.sendAndConfirm(tx, [mint]);
+
+        return mint.publicKey;
+    }
+
+    async function mintAuthority(mint: PublicKey): Promise<PublicKey | null> {
+        const data = Buffer.from((await banksClient.getAccount(mint)).data);
+        return data.readUInt32LE(0) === 1 ? new PublicKey(data.subarray(4, 36)) : null;
+    }
+
+    async function depositMintAuthority(vaultPda: PublicKey, mint: PublicKey): Promise<PublicKey> {
+        const [recordPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("authority"), vaultPda.toBuffer(), mint.toBuffer(), Buffer.from([0])],
+            program.programId
+        );
+
+        await program.methods
+            .depositMintAuthority({ mintTokens: {} })
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                mint,
+                authorityRecord: recordPda,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        return recordPda;
+    }
+
+    it("claimAuthority - Recipient takes over a custodied mint authority", async () => {
+        const recipient = Keypair.generate();
+        const vaultPda = await initializeVault(new BN(2100001), recipient.publicKey, "Token Founder");
+        const mint = await createMint();
+
+        const recordPda = await depositMintAuthority(vaultPda, mint);
+
+        let vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.custodiedAuthorities).to.equal(1);
+        expect((await mintAuthority(mint)).toString()).to.equal(vaultPda.toString());
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        const claimAccounts = {
+            vault: vaultPda,
+            claimant: recipient.publicKey,
+            authorityRecord: recordPda,
+            mint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            programData: null,
+            bpfLoader: null,
+        };
+
+        // The loader account must be the real BPF upgradeable loader
+        try {
+            await program.methods
+                .claimAuthority()
+                .accounts({ ...claimAccounts, bpfLoader: SystemProgram.programId })
+                .signers([recipient])
+                .rpc();
+            expect.fail("Should have thrown ConstraintAddress error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("ConstraintAddress");
+        }
+
+        await program.methods
+            .claimAuthority()
+            .accounts(claimAccounts)
+            .signers([recipient])
+            .rpc();
+
+        vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.custodiedAuthorities).to.equal(0);
+        expect((await mintAuthority(mint)).toString()).to.equal(recipient.publicKey.toString());
+        expect(await banksClient.getAccount(recordPda)).to.be.null;
+    });
+
+    it("reclaimAuthority - Owner takes a custodied authority back before release", async () => {
+        const recipient = Keypair.generate().publicKey;
+        const vaultPda = await initializeVault(new BN(2100002), recipient, "Changed Plans");
+        const mint = await createMint();
+
+        const recordPda = await depositMintAuthority(vaultPda, mint);
+
+        // Only the owner may take it back
+        const stranger = Keypair.generate();
+        try {
+            await program.methods
+                .reclaimAuthority()
+                .accounts({
+                    vault: vaultPda,
+                    owner: stranger.publicKey,
+                    authorityRecord: recordPda,
+                    mint,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    programData: null,
+                    bpfLoader: null,
+                    proposal: null,
+                })
+                .signers([stranger])
+                .rpc();
+            expect.fail("Should have thrown Unauthorized error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("Unauthorized");
+        }
+
+        await program.methods
+            .reclaimAuthority()
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                authorityRecord: recordPda,
+                mint,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                programData: null,
+                bpfLoader: null,
+                proposal: null,
+            })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.custodiedAuthorities).to.equal(0);
+        expect((await mintAuthority(mint)).toString()).to.equal(payer.publicKey.toString());
+        expect(await banksClient.getAccount(recordPda)).to.be.null;
+    });
+
+    it("cascadeClaimAuthority - Anyone forwards a custodied authority into a recipient vault", async () => {
+        const heirRecipient = Keypair.generate().publicKey;
+        const heirVault = await initializeVault(new BN(2100003), heirRecipient, "Heir Vault");
+        const sourceVault = await initializeVault(new BN(2100004), heirVault, "Parent Vault");
+        const mint = await createMint();
+
+        const recordPda = await depositMintAuthority(sourceVault, mint);
+        const [targetRecordPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("authority"), heirVault.toBuffer(), mint.toBuffer(), Buffer.from([0])],
+            program.programId
+        );
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: sourceVault, owner: payer.publicKey })
+            .rpc();
+
+        await program.methods
+            .cascadeClaimAuthority()
+            .accounts({
+                vault: sourceVault,
+                targetVault: heirVault,
+                caller: payer.publicKey,
+                authorityRecord: recordPda,
+                targetAuthorityRecord: targetRecordPda,
+                mint,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                programData: null,
+                bpfLoader: null,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts([
+                { pubkey: heirRecipient, isSigner: false, isWritable: false },
+            ])
+            .rpc();
+
+        expect((await program.account.vault.fetch(sourceVault)).custodiedAuthorities).to.equal(0);
+        expect((await program.account.vault.fetch(heirVault)).custodiedAuthorities).to.equal(1);
+        expect((await mintAuthority(mint)).toString()).to.equal(heirVault.toString());
+        expect(await banksClient.getAccount(recordPda)).to.be.null;
+
+        const record = await program.account.authorityRecord.fetch(targetRecordPda);
+        expect(record.target.toString()).to.equal(mint.toString());
+        expect(record.kind).to.deep.equal({ mintTokens: {} });
+    });
+});
@@ -0,0 +1,87 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+describe("Vault Cascading Inheritance Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    function vaultAddress(vaultSeed: BN) {
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        return vaultPda;
+    }
+
+    it("cascadeClaimSol - Requires the source vault to be released", async () => {
+        const heirSeed = new BN(1600001);
+        const sourceSeed = new BN(1600002);
+        const heirVault = vaultAddress(heirSeed);
+        const sourceVault = vaultAddress(sourceSeed);
+
+        // The heir's own switch names a wallet
+        await program.methods
+            .initializeVault(
+                heirSeed,
+                "cid", "key", Keypair.generate().publicKey, new BN(300), new BN(0), "Heir Vault", new BN(0)
+            )
+            .accounts({
+                vault: heirVault,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // The parent's vault names the heir's vault as recipient
+        await program.methods
+            .initializeVault(
+                sourceSeed,
+                "cid", "key", heirVault, new BN(300), new BN(0), "Parent Vault", new BN(1000000)
+            )
+            .accounts({
+                vault: sourceVault,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const heirRecipient = (await program.account.vault.fetch(heirVault)).recipient;
+
+        try {
+            await program.methods
+                .cascadeClaimSol()
+                .accounts({
+                    vault: sourceVault,
+                    targetVault: heirVault,
+                    allocationTable: null,
+                    caller: payer.publicKey,
+                })
+                .remainingAccounts([
+                    { pubkey: heirRecipient, isSigner: false, isWritable: false },
+                ])
+                .rpc();
+            expect.fail("Should have thrown NotReleased error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NotReleased");
+        }
+    });
+});
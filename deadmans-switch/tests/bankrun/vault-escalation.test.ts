@@ -0,0 +1,269 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+describe("Vault Tiered Escalation Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    it("setEscalationTiers - Carves tier slices from locked SOL", async () => {
+        const vaultSeed = new BN(1400001);
+        const recipient = Keypair.generate().publicKey;
+        const spouse = Keypair.generate().publicKey;
+        const children = Keypair.generate().publicKey;
+        const day = 24 * 60 * 60;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [planPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("escalation"), vaultPda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(365 * day), new BN(0), "Staged Estate", new BN(3000000)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setEscalationTiers([
+                {
+                    inactivity: new BN(30 * day),
+                    beneficiary: spouse,
+                    encryptedKey: "spouseKey",
+                    lamports: new BN(0),
+                    tokens: new BN(0),
+                    bountyLamports: new BN(5000),
+                    claimNotBefore: new BN(0),
+                },
+                {
+                    inactivity: new BN(180 * day),
+                    beneficiary: children,
+                    encryptedKey: "childrenKey",
+                    lamports: new BN(2000000),
+                    tokens: new BN(0),
+                    bountyLamports: new BN(5000),
+                    claimNotBefore: new BN(0),
+                },
+            ])
+            .accounts({
+                vault: vaultPda,
+                escalationPlan: planPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.hasEscalationPlan).to.be.true;
+        // Remainder stays with the main recipient
+        expect(vaultAccount.lockedLamports.toNumber()).to.equal(1000000);
+
+        const plan = await program.account.escalationPlan.fetch(planPda);
+        expect(plan.tiers.length).to.equal(2);
+        expect(plan.nextTier).to.equal(0);
+
+        // First tier is not due yet; the main deadline hasn't passed either
+        try {
+            await program.methods
+                .triggerRelease()
+                .accounts({
+                    vault: vaultPda,
+                    hunter: payer.publicKey,
+                    estate: null,
+                    escalationPlan: planPda,
+                })
+                .rpc();
+            expect.fail("Should have thrown NotExpired error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NotExpired");
+        }
+
+        // Tiers run on inactivity, so the vault can't become a time capsule
+        const clock = await banksClient.getClock();
+        try {
+            await program.methods
+                .setReleaseMode({ fixedDate: {} }, new BN(Number(clock.unixTimestamp) + 365 * day))
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown ReleaseModeMismatch error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("ReleaseModeMismatch");
+        }
+    });
+
+    it("setEscalationTiers - Rejects unordered tiers", async () => {
+        const vaultSeed = new BN(1400002);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [planPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("escalation"), vaultPda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Bad Tiers", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const tier = (inactivity: number) => ({
+            inactivity: new BN(inactivity),
+            beneficiary: Keypair.generate().publicKey,
+            encryptedKey: "key",
+            lamports: new BN(0),
+            tokens: new BN(0),
+            bountyLamports: new BN(0),
+            claimNotBefore: new BN(0),
+        });
+
+        try {
+            await program.methods
+                .setEscalationTiers([tier(200), tier(100)])
+                .accounts({
+                    vault: vaultPda,
+                    escalationPlan: planPda,
+                    owner: payer.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            expect.fail("Should have thrown InvalidTierOrder error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("InvalidTierOrder");
+        }
+
+        // Each tier beneficiary's claim-not-before date must be a valid timestamp
+        try {
+            await program.methods
+                .setEscalationTiers([{ ...tier(100), claimNotBefore: new BN(-1) }])
+                .accounts({
+                    vault: vaultPda,
+                    escalationPlan: planPda,
+                    owner: payer.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            expect.fail("Should have thrown InvalidReleaseDate error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("InvalidReleaseDate");
+        }
+    });
+
+    it("claimAndClose - Blocked while tiers hold unclaimed slices", async () => {
+        const vaultSeed = new BN(1400003);
+        const recipient = Keypair.generate();
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [planPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("escalation"), vaultPda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient.publicKey, new BN(300), new BN(0), "Early Sweep", new BN(2000000)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setEscalationTiers([
+                {
+                    inactivity: new BN(3600),
+                    beneficiary: Keypair.generate().publicKey,
+                    encryptedKey: "tierKey",
+                    lamports: new BN(1000000),
+                    tokens: new BN(0),
+                    bountyLamports: new BN(5000),
+                    claimNotBefore: new BN(0),
+                },
+            ])
+            .accounts({
+                vault: vaultPda,
+                escalationPlan: planPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        try {
+            await program.methods
+                .claimAndClose()
+                .accounts({ vault: vaultPda, recipient: recipient.publicKey, escalationPlan: null })
+                .signers([recipient])
+                .rpc();
+            expect.fail("Should have thrown EscalationPlanRequired error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("EscalationPlanRequired");
+        }
+
+        try {
+            await program.methods
+                .claimAndClose()
+                .accounts({ vault: vaultPda, recipient: recipient.publicKey, escalationPlan: planPda })
+                .signers([recipient])
+                .rpc();
+            expect.fail("Should have thrown TiersUnclaimed error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("TiersUnclaimed");
+        }
+    });
+});
@@ -0,0 +1,206 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+describe("Vault Estate (Shared Heartbeat) Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+    let estatePda: PublicKey;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+
+        [estatePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("estate"), payer.publicKey.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeEstate(new BN(300))
+            .accounts({
+                estate: estatePda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+    });
+
+    it("linkEstate - Owner links vault to estate", async () => {
+        const vaultSeed = new BN(1100001);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Estate Link", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .linkEstate()
+            .accounts({
+                vault: vaultPda,
+                estate: estatePda,
+                owner: payer.publicKey,
+            })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.estate?.toString()).to.equal(estatePda.toString());
+
+        const estateAccount = await program.account.estate.fetch(estatePda);
+        expect(estateAccount.linkedVaults).to.equal(1);
+
+        // Single estate ping keeps every linked vault alive
+        await program.methods
+            .pingEstate()
+            .accounts({
+                estate: estatePda,
+                owner: payer.publicKey,
+            })
+            .rpc();
+
+        // The vault's own timer no longer counts, so pinging it is refused
+        try {
+            await program.methods
+                .ping()
+                .accounts({ vault: vaultPda, signer: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown LinkedToEstate error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("LinkedToEstate");
+        }
+    });
+
+    it("triggerRelease - Linked vault requires its estate", async () => {
+        const vaultSeed = new BN(1100002);
+        const recipient = Keypair.generate().publicKey;
+        const hunter = Keypair.generate();
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(1), new BN(0), "Estate Trigger", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .linkEstate()
+            .accounts({
+                vault: vaultPda,
+                estate: estatePda,
+                owner: payer.publicKey,
+            })
+            .rpc();
+
+        try {
+            await program.methods
+                .triggerRelease()
+                .accounts({
+                    vault: vaultPda,
+                    hunter: hunter.publicKey,
+                    estate: null,
+                })
+                .signers([hunter])
+                .rpc();
+            expect.fail("Should have thrown EstateMismatch error");
+        } catch (err: any) {
+            expect(err.error.errorCode.code).to.equal("EstateMismatch");
+        }
+    });
+
+    it("closeVault - Closing a linked vault decrements the estate count", async () => {
+        const vaultSeed = new BN(1100003);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Estate Close", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .linkEstate()
+            .accounts({
+                vault: vaultPda,
+                estate: estatePda,
+                owner: payer.publicKey,
+            })
+            .rpc();
+
+        const linkedBefore = (await program.account.estate.fetch(estatePda)).linkedVaults;
+
+        // A linked vault cannot be closed without its estate
+        try {
+            await program.methods
+                .closeVault()
+                .accounts({ vault: vaultPda, owner: payer.publicKey, estate: null })
+                .rpc();
+            expect.fail("Should have thrown EstateMismatch error");
+        } catch (err: any) {
+            expect(err.error.errorCode.code).to.equal("EstateMismatch");
+        }
+
+        await program.methods
+            .closeVault()
+            .accounts({ vault: vaultPda, owner: payer.publicKey, estate: estatePda })
+            .rpc();
+
+        const estateAccount = await program.account.estate.fetch(estatePda);
+        expect(estateAccount.linkedVaults).to.equal(linkedBefore - 1);
+        expect(await banksClient.getAccount(vaultPda)).to.be.null;
+    });
+});
@@ -0,0 +1,298 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import { Clock } from "solana-bankrun";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+describe("Vault Claim Deadline & Fallback Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    // Move the bank clock forward so claim windows can lapse
+    async function warp(seconds: number) {
+        const clock = await banksClient.getClock();
+        context.setClock(
+            new Clock(
+                clock.slot,
+                clock.epochStartTimestamp,
+                clock.epoch,
+                clock.leaderScheduleEpoch,
+                clock.unixTimestamp + BigInt(seconds)
+            )
+        );
+    }
+
+    // Anyone may pass claim rights; a fresh caller also keeps repeated calls distinct
+    async function passClaimRights(vaultPda: PublicKey, allocationTable: PublicKey | null = null) {
+        const caller = Keypair.generate();
+        await program.methods
+            .passClaimRights()
+            .accounts({ vault: vaultPda, caller: caller.publicKey, allocationTable })
+            .signers([caller])
+            .rpc();
+    }
+
+    // Let the one-second heartbeat lapse, then release the vault
+    async function release(vaultPda: PublicKey) {
+        await warp(2);
+        const hunter = Keypair.generate();
+        await program.methods
+            .triggerRelease()
+            .accounts({ vault: vaultPda, hunter: hunter.publicKey, estate: null })
+            .signers([hunter])
+            .rpc();
+    }
+
+    async function claimSol(vaultPda: PublicKey, recipient: Keypair) {
+        await program.methods
+            .claimSol()
+            .accounts({
+                vault: vaultPda,
+                recipient: recipient.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([recipient])
+            .rpc();
+    }
+
+    it("passClaimRights - Claim rights pass down the fallback list in order", async () => {
+        const vaultSeed = new BN(1900001);
+        const heir = Keypair.generate();
+        const secondHeir = Keypair.generate();
+        const charity = Keypair.generate();
+        const deadline = 60;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", heir.publicKey, new BN(1), new BN(0), "Fallback Estate", new BN(1000000)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setFallbackRecipients([secondHeir.publicKey, charity.publicKey], new BN(deadline))
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        await release(vaultPda);
+
+        // The first recipient's window is still open
+        try {
+            await passClaimRights(vaultPda);
+            expect.fail("Should have thrown ClaimDeadlineNotReached error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("ClaimDeadlineNotReached");
+        }
+
+        await warp(deadline + 1);
+        await passClaimRights(vaultPda);
+
+        let account = await program.account.vault.fetch(vaultPda);
+        expect(account.recipient.toString()).to.equal(secondHeir.publicKey.toString());
+        expect(account.custodyLog.length).to.equal(1);
+        expect(account.custodyLog[0].from.toString()).to.equal(heir.publicKey.toString());
+        expect(account.custodyLog[0].to.toString()).to.equal(secondHeir.publicKey.toString());
+
+        // The lapsed recipient can no longer claim
+        try {
+            await claimSol(vaultPda, heir);
+            expect.fail("Should have thrown NotRecipient error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NotRecipient");
+        }
+
+        // The handoff restarts the claim window for the new recipient
+        try {
+            await passClaimRights(vaultPda);
+            expect.fail("Should have thrown ClaimDeadlineNotReached error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("ClaimDeadlineNotReached");
+        }
+
+        await warp(deadline + 1);
+        await passClaimRights(vaultPda);
+
+        account = await program.account.vault.fetch(vaultPda);
+        expect(account.recipient.toString()).to.equal(charity.publicKey.toString());
+        expect(account.custodyLog.length).to.equal(2);
+        expect(account.custodyLog[1].from.toString()).to.equal(secondHeir.publicKey.toString());
+
+        // The list is exhausted; the last fallback keeps its rights
+        await warp(deadline + 1);
+        try {
+            await passClaimRights(vaultPda);
+            expect.fail("Should have thrown NoFallbackRecipient error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NoFallbackRecipient");
+        }
+    });
+
+    it("claimSol - Recipient can still claim after the deadline until rights pass", async () => {
+        const vaultSeed = new BN(1900002);
+        const heir = Keypair.generate();
+        const fallback = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", heir.publicKey, new BN(1), new BN(0), "Late Claim", new BN(1000000)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setFallbackRecipients([fallback], new BN(60))
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        await release(vaultPda);
+
+        await warp(61);
+        await claimSol(vaultPda, heir);
+
+        const account = await program.account.vault.fetch(vaultPda);
+        expect(account.lockedLamports.toNumber()).to.equal(0);
+        expect(account.recipient.toString()).to.equal(heir.publicKey.toString());
+
+        // Nothing is left to pass on
+        try {
+            await passClaimRights(vaultPda);
+            expect.fail("Should have thrown NothingToClaim error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NothingToClaim");
+        }
+    });
+
+    it("passClaimRights - Unclaimed allocation shares pass to the fallback", async () => {
+        const vaultSeed = new BN(1900003);
+        const heirA = Keypair.generate();
+        const heirB = Keypair.generate().publicKey;
+        const fallback = Keypair.generate();
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [allocationPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("allocation"), vaultPda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", heirA.publicKey, new BN(1), new BN(0), "Split Fallback", new BN(1000000)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setAllocations([
+                { mint: null, shares: [{ recipient: heirA.publicKey, bps: 6000 }, { recipient: heirB, bps: 4000 }] },
+            ])
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                allocationTable: allocationPda,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setFallbackRecipients([fallback.publicKey], new BN(60))
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        await release(vaultPda);
+
+        await program.methods
+            .claimSol()
+            .accounts({
+                vault: vaultPda,
+                allocationTable: allocationPda,
+                recipient: heirA.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([heirA])
+            .rpc();
+
+        await warp(61);
+
+        try {
+            await passClaimRights(vaultPda);
+            expect.fail("Should have thrown AllocationTableRequired error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("AllocationTableRequired");
+        }
+
+        await passClaimRights(vaultPda, allocationPda);
+
+        const table = await program.account.allocationTable.fetch(allocationPda);
+        expect(table.assets[0].shares.length).to.equal(1);
+        expect(table.assets[0].shares[0].recipient.toString()).to.equal(fallback.publicKey.toString());
+        expect(table.assets[0].shares[0].bps).to.equal(4000);
+
+        // The lapsed heir's share is now the fallback's to claim
+        await program.methods
+            .claimSol()
+            .accounts({
+                vault: vaultPda,
+                allocationTable: allocationPda,
+                recipient: fallback.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([fallback])
+            .rpc();
+
+        const account = await program.account.vault.fetch(vaultPda);
+        expect(account.lockedLamports.toNumber()).to.equal(0);
+    });
+});
@@ -0,0 +1,328 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import { Clock } from "solana-bankrun";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+describe("Vault Legacy Instruction Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    it("setLegacyInstructions - Stores whitelisted steps signed by the vault", async () => {
+        const vaultSeed = new BN(1800001);
+        const recipient = Keypair.generate().publicKey;
+        const memoProgram = new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [legacyPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("legacy"), vaultPda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Legacy Estate", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Programs outside the whitelist are rejected
+        try {
+            await program.methods
+                .setLegacyInstructions([
+                    { programId: Keypair.generate().publicKey, accounts: [], data: Buffer.from([]) },
+                ])
+                .accounts({
+                    vault: vaultPda,
+                    legacyPlan: legacyPda,
+                    owner: payer.publicKey,
+                    proposal: null,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            expect.fail("Should have thrown ProgramNotWhitelisted error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("ProgramNotWhitelisted");
+        }
+
+        await program.methods
+            .setLegacyInstructions([
+                {
+                    programId: memoProgram,
+                    accounts: [{ pubkey: vaultPda, isSigner: true, isWritable: false }],
+                    data: Buffer.from("Estate settled"),
+                },
+            ])
+            .accounts({
+                vault: vaultPda,
+                legacyPlan: legacyPda,
+                owner: payer.publicKey,
+                proposal: null,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.hasLegacyPlan).to.be.true;
+
+        const plan = await program.account.legacyPlan.fetch(legacyPda);
+        expect(plan.steps.length).to.equal(1);
+        expect(plan.nextStep).to.equal(0);
+
+        // A destroy action would leave the stored steps unreachable
+        try {
+            await program.methods
+                .setReleaseAction({ destroyReturnTokens: {} })
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown ReleaseActionConflict error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("ReleaseActionConflict");
+        }
+
+        // Steps only run after release
+        try {
+            await program.methods
+                .executeLegacyStep()
+                .accounts({
+                    vault: vaultPda,
+                    legacyPlan: legacyPda,
+                    executor: payer.publicKey,
+                })
+                .remainingAccounts([
+                    { pubkey: vaultPda, isSigner: false, isWritable: false },
+                    { pubkey: memoProgram, isSigner: false, isWritable: false },
+                ])
+                .rpc();
+            expect.fail("Should have thrown NotReleased error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NotReleased");
+        }
+
+        // Stored steps could still move committed assets, so the plan must be cleared first
+        try {
+            await program.methods
+                .makeIrrevocable()
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown LegacyStepsPending error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("LegacyStepsPending");
+        }
+    });
+
+    it("succeed - Heir discards the previous owner's plan, warning and notice", async () => {
+        const vaultSeed = new BN(1800002);
+        const memoProgram = new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [legacyPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("legacy"), vaultPda.toBuffer()],
+            program.programId
+        );
+
+        // Payer names itself recipient so it can sign as the heir
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", payer.publicKey, new BN(300), new BN(0), "Inherited Plan", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setLegacyInstructions([
+                {
+                    programId: memoProgram,
+                    accounts: [{ pubkey: vaultPda, isSigner: true, isWritable: false }],
+                    data: Buffer.from("Old owner's wish"),
+                },
+            ])
+            .accounts({
+                vault: vaultPda,
+                legacyPlan: legacyPda,
+                owner: payer.publicKey,
+                proposal: null,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setExpiryWarning(new BN(60), new BN(1000), new BN(0))
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        await program.methods
+            .setReleaseNotice(new BN(0), "Check your inbox")
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        try {
+            await program.methods
+                .succeed(Keypair.generate().publicKey, "newCid", "newKey")
+                .accounts({ vault: vaultPda, heir: payer.publicKey, estate: null, legacyPlan: null })
+                .rpc();
+            expect.fail("Should have thrown LegacyPlanRequired error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("LegacyPlanRequired");
+        }
+
+        await program.methods
+            .succeed(Keypair.generate().publicKey, "newCid", "newKey")
+            .accounts({ vault: vaultPda, heir: payer.publicKey, estate: null, legacyPlan: legacyPda })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.hasLegacyPlan).to.be.false;
+        expect(vaultAccount.warningWindow.toNumber()).to.equal(0);
+        expect(vaultAccount.notificationBounty.toNumber()).to.equal(0);
+        expect(vaultAccount.releaseNoticeMemo).to.equal("");
+        expect(await banksClient.getAccount(legacyPda)).to.be.null;
+    });
+
+    it("skipLegacyStep - Anyone, only after the delay, can skip stuck steps", async () => {
+        const vaultSeed = new BN(1800003);
+        const recipient = Keypair.generate();
+        const stranger = Keypair.generate();
+        const memoProgram = new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+        const skipDelay = 30 * 24 * 60 * 60;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [legacyPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("legacy"), vaultPda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient.publicKey, new BN(300), new BN(0), "Stuck Legacy", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const step = {
+            programId: memoProgram,
+            accounts: [{ pubkey: vaultPda, isSigner: true, isWritable: false }],
+            data: Buffer.from("Estate settled"),
+        };
+        await program.methods
+            .setLegacyInstructions([step, step])
+            .accounts({
+                vault: vaultPda,
+                legacyPlan: legacyPda,
+                owner: payer.publicKey,
+                proposal: null,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        const skip = (caller: Keypair) =>
+            program.methods
+                .skipLegacyStep()
+                .accounts({ vault: vaultPda, legacyPlan: legacyPda, caller: caller.publicKey })
+                .signers([caller])
+                .rpc();
+
+        // Everyone, the recipient included, must wait out the skip delay
+        for (const caller of [stranger, recipient]) {
+            try {
+                await skip(caller);
+                expect.fail("Should have thrown LegacySkipTooEarly error");
+            } catch (err: any) {
+                expect(err.error?.errorCode?.code).to.equal("LegacySkipTooEarly");
+            }
+        }
+
+        const clock = await banksClient.getClock();
+        context.setClock(
+            new Clock(
+                clock.slot,
+                clock.epochStartTimestamp,
+                clock.epoch,
+                clock.leaderScheduleEpoch,
+                clock.unixTimestamp + BigInt(skipDelay + 1)
+            )
+        );
+
+        await skip(recipient);
+
+        let plan = await program.account.legacyPlan.fetch(legacyPda);
+        expect(plan.nextStep).to.equal(1);
+        expect(plan.steps[0].executedAt.toNumber()).to.be.lessThan(0);
+        expect(plan.steps[1].executedAt.toNumber()).to.equal(0);
+
+        await skip(stranger);
+
+        plan = await program.account.legacyPlan.fetch(legacyPda);
+        expect(plan.nextStep).to.equal(2);
+
+        // The finished plan no longer blocks closing the vault
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.hasLegacyPlan).to.be.false;
+
+        try {
+            await skip(recipient);
+            expect.fail("Should have thrown NoPendingLegacySteps error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NoPendingLegacySteps");
+        }
+    });
+});
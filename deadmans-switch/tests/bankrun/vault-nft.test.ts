@@ -0,0 +1,529 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import { Clock } from "solana-bankrun";
+import {
+    PublicKey,
+    SystemProgram,
+    Keypair,
+    Transaction,
+    TransactionInstruction,
+    LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { BN, utils } from "@coral-xyz/anchor";
+
+const TOKEN_PROGRAM_ID = utils.token.TOKEN_PROGRAM_ID;
+const ASSOCIATED_PROGRAM_ID = utils.token.ASSOCIATED_PROGRAM_ID;
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+// Token Metadata `TokenStandard` discriminants
+const NON_FUNGIBLE = 0;
+const PROGRAMMABLE_NON_FUNGIBLE = 4;
+
+describe("Vault NFT Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    function findVault(vaultSeed: BN): PublicKey {
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        return vaultPda;
+    }
+
+    async function initializeVault(vaultSeed: BN, recipient: PublicKey, name: string) {
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), name, new BN(0)
+            )
+            .accounts({
+                vault: findVault(vaultSeed),
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+    }
+
+    function createAtaIx(owner: PublicKey, mint: PublicKey): TransactionInstruction {
+        return new TransactionInstruction({
+            programId: ASSOCIATED_PROGRAM_ID,
+            keys: [
+                { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+                { pubkey: utils.token.associatedAddress({ mint, owner }), isSigner: false, isWritable: true },
+                { pubkey: owner, isSigner: false, isWritable: false },
+                { pubkey: mint, isSigner: false, isWritable: false },
+                { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+            ],
+            // CreateIdempotent
+            data: Buffer.from([1]),
+        });
+    }
+
+    // Mint a single token (decimals 0, supply 1) into `holder`'s associated token account
+    async function mintNft(holder: PublicKey): Promise<PublicKey> {
+        const mint = Keypair.generate();
+        const rent = await banksClient.getRent();
+
+        // InitializeMint2: decimals 0, payer as mint authority, no freeze authority
+        const initMintData = Buffer.concat([
+            Buffer.from([20, 0]),
+            payer.publicKey.toBuffer(),
+            Buffer.from([0]),
+        ]);
+        // MintTo: one token
+        const mintToData = Buffer.concat([Buffer.from([7]), new BN(1).toArrayLike(Buffer, "le", 8)]);
+
+        const tx = new Transaction().add(
+            SystemProgram.createAccount({
+                fromPubkey: payer.publicKey,
+                newAccountPubkey: mint.publicKey,
+                lamports: Number(rent.minimumBalance(BigInt(82))),
+                space: 82,
+                programId: TOKEN_PROGRAM_ID,
+            }),
+            new TransactionInstruction({
+                programId: TOKEN_PROGRAM_ID,
+                keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+                data: initMintData,
+            }),
+            createAtaIx(holder, mint.publicKey),
+            new TransactionInstruction({
+                programId: TOKEN_PROGRAM_ID,
+                keys: [
+                    { pubkey: mint.publicKey, isSigner: false, isWritable: true },
+                    {
+                        pubkey: utils.token.associatedAddress({ mint: mint.publicKey, owner: holder }),
+                        isSigner: false,
+                        isWritable: true,
+                    },
+                    { pubkey: payer.publicKey, isSigner: true, isWritable: false },
+                ],
+                data: mintToData,
+            })
+        );
+        await provider.sendAndConfirm(tx, [mint]);
+
+        return mint.publicKey;
+    }
+
+    // Write a Token Metadata account for `mint` directly into the bank
+    function setMetadata(mint: PublicKey, tokenStandard: number): PublicKey {
+        const [metadata] = PublicKey.findProgramAddressSync(
+            [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+            TOKEN_METADATA_PROGRAM_ID
+        );
+        const borshString = (s: string) => {
+            const len = Buffer.alloc(4);
+            len.writeUInt32LE(s.length);
+            return Buffer.concat([len, Buffer.from(s)]);
+        };
+
+        const data = Buffer.alloc(679);
+        Buffer.concat([
+            Buffer.from([4]), // Key::MetadataV1
+            payer.publicKey.toBuffer(), // update_authority
+            mint.toBuffer(),
+            borshString("Heirloom"),
+            borshString("HEIR"),
+            borshString("https://example.com/heirloom.json"),
+            Buffer.from([0, 0]), // seller_fee_basis_points
+            Buffer.from([0]), // creators: None
+            Buffer.from([0, 1]), // primary_sale_happened, is_mutable
+            Buffer.from([0]), // edition_nonce: None
+            Buffer.from([1, tokenStandard]),
+            Buffer.from([0, 0, 0, 0]), // collection, uses, collection_details, programmable_config
+        ]).copy(data);
+
+        context.setAccount(metadata, {
+            lamports: LAMPORTS_PER_SOL,
+            data,
+            owner: TOKEN_METADATA_PROGRAM_ID,
+            executable: false,
+        });
+
+        return metadata;
+    }
+
+    async function fund(account: PublicKey) {
+        await provider.sendAndConfirm(
+            new Transaction().add(
+                SystemProgram.transfer({
+                    fromPubkey: payer.publicKey,
+                    toPubkey: account,
+                    lamports: LAMPORTS_PER_SOL,
+                })
+            )
+        );
+    }
+
+    async function tokenBalance(tokenAccount: PublicKey): Promise<number> {
+        const account = await banksClient.getAccount(tokenAccount);
+        return Number(Buffer.from(account.data).readBigUInt64LE(64));
+    }
+
+    function lockNftAccounts(vaultPda: PublicKey, mint: PublicKey, metadata: PublicKey) {
+        const [nftRecordPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("nft"), vaultPda.toBuffer(), mint.toBuffer()],
+            program.programId
+        );
+        return {
+            vault: vaultPda,
+            owner: payer.publicKey,
+            mint,
+            metadata,
+            nftRecord: nftRecordPda,
+            ownerTokenAccount: utils.token.associatedAddress({ mint, owner: payer.publicKey }),
+            vaultTokenAccount: utils.token.associatedAddress({ mint, owner: vaultPda }),
+            edition: null,
+            ownerTokenRecord: null,
+            vaultTokenRecord: null,
+            authorizationRules: null,
+            authorizationRulesProgram: null,
+            tokenMetadataProgram: null,
+            sysvarInstructions: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+        };
+    }
+
+    function claimNftAccounts(vaultPda: PublicKey, mint: PublicKey, claimant: PublicKey) {
+        const [nftRecordPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("nft"), vaultPda.toBuffer(), mint.toBuffer()],
+            program.programId
+        );
+        return {
+            vault: vaultPda,
+            claimant,
+            mint,
+            nftRecord: nftRecordPda,
+            vaultTokenAccount: utils.token.associatedAddress({ mint, owner: vaultPda }),
+            claimantTokenAccount: utils.token.associatedAddress({ mint, owner: claimant }),
+            metadata: null,
+            edition: null,
+            vaultTokenRecord: null,
+            claimantTokenRecord: null,
+            authorizationRules: null,
+            authorizationRulesProgram: null,
+            tokenMetadataProgram: null,
+            sysvarInstructions: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+        };
+    }
+
+    it("lockNft / claimNft - Standard NFT moves to the recipient", async () => {
+        const vaultSeed = new BN(2000001);
+        const recipient = Keypair.generate();
+        const vaultPda = findVault(vaultSeed);
+
+        await initializeVault(vaultSeed, recipient.publicKey, "Heirloom");
+
+        const mint = await mintNft(payer.publicKey);
+        const metadata = setMetadata(mint, NON_FUNGIBLE);
+        const accounts = lockNftAccounts(vaultPda, mint, metadata);
+
+        // Only the vault owner may lock, even one paying its own way
+        const stranger = Keypair.generate();
+        await fund(stranger.publicKey);
+        try {
+            await program.methods
+                .lockNft()
+                .accounts({ ...accounts, owner: stranger.publicKey })
+                .signers([stranger])
+                .rpc();
+            expect.fail("Should have thrown Unauthorized error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("Unauthorized");
+        }
+
+        await program.methods.lockNft().accounts(accounts).rpc();
+
+        let vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.lockedNfts).to.equal(1);
+        expect(await tokenBalance(accounts.vaultTokenAccount)).to.equal(1);
+        expect(await tokenBalance(accounts.ownerTokenAccount)).to.equal(0);
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        // The recipient pays for their own token account
+        await fund(recipient.publicKey);
+
+        const claimAccounts = claimNftAccounts(vaultPda, mint, recipient.publicKey);
+        await program.methods
+            .claimNft()
+            .accounts(claimAccounts)
+            .signers([recipient])
+            .rpc();
+
+        vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.lockedNfts).to.equal(0);
+        expect(await tokenBalance(claimAccounts.claimantTokenAccount)).to.equal(1);
+        expect(await banksClient.getAccount(claimAccounts.vaultTokenAccount)).to.be.null;
+        expect(await banksClient.getAccount(claimAccounts.nftRecord)).to.be.null;
+    });
+
+    it("lockNft / claimNft - Programmable NFT requires the Token Metadata accounts", async () => {
+        const vaultSeed = new BN(2000002);
+        const recipient = Keypair.generate();
+        const vaultPda = findVault(vaultSeed);
+
+        await initializeVault(vaultSeed, recipient.publicKey, "Programmable");
+
+        const mint = await mintNft(payer.publicKey);
+        const metadata = setMetadata(mint, PROGRAMMABLE_NON_FUNGIBLE);
+
+        try {
+            await program.methods
+                .lockNft()
+                .accounts(lockNftAccounts(vaultPda, mint, metadata))
+                .rpc();
+            expect.fail("Should have thrown ProgrammableAccountsRequired error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("ProgrammableAccountsRequired");
+        }
+
+        // Stand in for a pNFT locked through Token Metadata: the vault holds it
+        // and its record routes the claim through a programmable transfer
+        const pnftMint = await mintNft(vaultPda);
+        const claimAccounts = claimNftAccounts(vaultPda, pnftMint, recipient.publicKey);
+        const recordData = await program.coder.accounts.encode("nftRecord", {
+            vault: vaultPda,
+            mint: pnftMint,
+            isProgrammable: true,
+            bump: PublicKey.findProgramAddressSync(
+                [Buffer.from("nft"), vaultPda.toBuffer(), pnftMint.toBuffer()],
+                program.programId
+            )[1],
+        });
+        context.setAccount(claimAccounts.nftRecord, {
+            lamports: LAMPORTS_PER_SOL,
+            data: recordData,
+            owner: program.programId,
+            executable: false,
+        });
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        await fund(recipient.publicKey);
+
+        try {
+            await program.methods
+                .claimNft()
+                .accounts(claimAccounts)
+                .signers([recipient])
+                .rpc();
+            expect.fail("Should have thrown ProgrammableAccountsRequired error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("ProgrammableAccountsRequired");
+        }
+
+        // The NFT stays in the vault
+        expect(await tokenBalance(claimAccounts.vaultTokenAccount)).to.equal(1);
+    });
+
+    it("passClaimRights - A locked NFT alone keeps claim rights moving", async () => {
+        const vaultSeed = new BN(2000003);
+        const heir = Keypair.generate().publicKey;
+        const fallback = Keypair.generate().publicKey;
+        const vaultPda = findVault(vaultSeed);
+
+        await initializeVault(vaultSeed, heir, "NFT Fallback");
+
+        const mint = await mintNft(payer.publicKey);
+        const metadata = setMetadata(mint, NON_FUNGIBLE);
+        await program.methods.lockNft().accounts(lockNftAccounts(vaultPda, mint, metadata)).rpc();
+
+        await program.methods
+            .setFallbackRecipients([fallback], new BN(60))
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        const clock = await banksClient.getClock();
+        context.setClock(
+            new Clock(
+                clock.slot,
+                clock.epochStartTimestamp,
+                clock.epoch,
+                clock.leaderScheduleEpoch,
+                clock.unixTimestamp + BigInt(61)
+            )
+        );
+
+        await program.methods
+            .passClaimRights()
+            .accounts({ vault: vaultPda, caller: payer.publicKey })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.lockedLamports.toNumber()).to.equal(0);
+        expect(vaultAccount.recipient.toString()).to.equal(fallback.toString());
+    });
+
+    it("reclaimNft - Owner takes a locked NFT back before release", async () => {
+        const vaultSeed = new BN(2000004);
+        const recipient = Keypair.generate().publicKey;
+        const vaultPda = findVault(vaultSeed);
+
+        await initializeVault(vaultSeed, recipient, "Changed Plans");
+
+        const mint = await mintNft(payer.publicKey);
+        const metadata = setMetadata(mint, NON_FUNGIBLE);
+        const accounts = lockNftAccounts(vaultPda, mint, metadata);
+        await program.methods.lockNft().accounts(accounts).rpc();
+
+        const reclaimAccounts = {
+            vault: vaultPda,
+            owner: payer.publicKey,
+            mint,
+            nftRecord: accounts.nftRecord,
+            vaultTokenAccount: accounts.vaultTokenAccount,
+            ownerTokenAccount: accounts.ownerTokenAccount,
+            metadata: null,
+            edition: null,
+            vaultTokenRecord: null,
+            ownerTokenRecord: null,
+            authorizationRules: null,
+            authorizationRulesProgram: null,
+            tokenMetadataProgram: null,
+            sysvarInstructions: null,
+            proposal: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+        };
+
+        // Only the owner may take it back
+        const stranger = Keypair.generate();
+        await fund(stranger.publicKey);
+        try {
+            await program.methods
+                .reclaimNft()
+                .accounts({
+                    ...reclaimAccounts,
+                    owner: stranger.publicKey,
+                    ownerTokenAccount: utils.token.associatedAddress({ mint, owner: stranger.publicKey }),
+                })
+                .signers([stranger])
+                .rpc();
+            expect.fail("Should have thrown Unauthorized error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("Unauthorized");
+        }
+
+        await program.methods.reclaimNft().accounts(reclaimAccounts).rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.lockedNfts).to.equal(0);
+        expect(await tokenBalance(accounts.ownerTokenAccount)).to.equal(1);
+        expect(await banksClient.getAccount(accounts.vaultTokenAccount)).to.be.null;
+        expect(await banksClient.getAccount(accounts.nftRecord)).to.be.null;
+
+        // With nothing left in custody the vault can be closed
+        await program.methods
+            .closeVault()
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+        expect(await banksClient.getAccount(vaultPda)).to.be.null;
+    });
+
+    it("cascadeClaimNft - Anyone forwards a locked NFT into a recipient vault", async () => {
+        const heirSeed = new BN(2000005);
+        const sourceSeed = new BN(2000006);
+        const heirRecipient = Keypair.generate().publicKey;
+        const heirVault = findVault(heirSeed);
+        const sourceVault = findVault(sourceSeed);
+
+        await initializeVault(heirSeed, heirRecipient, "Heir Vault");
+        await initializeVault(sourceSeed, heirVault, "Parent Vault");
+
+        const mint = await mintNft(payer.publicKey);
+        const metadata = setMetadata(mint, NON_FUNGIBLE);
+        const accounts = lockNftAccounts(sourceVault, mint, metadata);
+        await program.methods.lockNft().accounts(accounts).rpc();
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: sourceVault, owner: payer.publicKey })
+            .rpc();
+
+        // The recipient vault can't sign, so a third party runs the cascade
+        const caller = Keypair.generate();
+        await fund(caller.publicKey);
+
+        const [targetRecordPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("nft"), heirVault.toBuffer(), mint.toBuffer()],
+            program.programId
+        );
+        const targetTokenAccount = utils.token.associatedAddress({ mint, owner: heirVault });
+
+        await program.methods
+            .cascadeClaimNft()
+            .accounts({
+                vault: sourceVault,
+                targetVault: heirVault,
+                caller: caller.publicKey,
+                mint,
+                nftRecord: accounts.nftRecord,
+                targetNftRecord: targetRecordPda,
+                vaultTokenAccount: accounts.vaultTokenAccount,
+                targetTokenAccount,
+                metadata: null,
+                edition: null,
+                vaultTokenRecord: null,
+                targetTokenRecord: null,
+                authorizationRules: null,
+                authorizationRulesProgram: null,
+                tokenMetadataProgram: null,
+                sysvarInstructions: null,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts([
+                { pubkey: heirRecipient, isSigner: false, isWritable: false },
+            ])
+            .signers([caller])
+            .rpc();
+
+        expect((await program.account.vault.fetch(sourceVault)).lockedNfts).to.equal(0);
+        expect((await program.account.vault.fetch(heirVault)).lockedNfts).to.equal(1);
+        expect(await tokenBalance(targetTokenAccount)).to.equal(1);
+        expect(await banksClient.getAccount(accounts.nftRecord)).to.be.null;
+
+        const record = await program.account.nftRecord.fetch(targetRecordPda);
+        expect(record.vault.toString()).to.equal(heirVault.toString());
+        expect(record.isProgrammable).to.be.false;
+    });
+});
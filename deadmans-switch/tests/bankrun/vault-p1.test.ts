@@ -122,7 +122,7 @@ describe("Vault P1 Core Functionality Tests", () => {
 
         // Update vault
         await program.methods
-            .updateVault(recipient, new BN(600), newName)
+            .updateVault(recipient, new BN(600), newName, null, null)
             .accounts({
                 vault: vaultPda,
                 owner: payer.publicKey,
@@ -197,7 +197,7 @@ describe("Vault P1 Core Functionality Tests", () => {
         // Attacker tries to update
         try {
             await program.methods
-                .updateVault(recipient, new BN(600), "Hacked Name")
+                .updateVault(recipient, new BN(600), "Hacked Name", null, null)
                 .accounts({
                     vault: vaultPda,
                     owner: attacker.publicKey,
@@ -209,4 +209,179 @@ describe("Vault P1 Core Functionality Tests", () => {
             expect(err).to.exist;
         }
     });
+
+    it("updateVault - Joint vault requires co-owner signatures", async () => {
+        const vaultSeed = new BN(770001);
+        const recipient = Keypair.generate().publicKey;
+        const coOwner = Keypair.generate();
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Joint Vault", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Both owners must sign administrative changes; release once both are inactive
+        await program.methods
+            .setCoOwners([coOwner.publicKey], { all: {} }, 2)
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+            })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.coOwners.length).to.equal(1);
+        expect(vaultAccount.adminThreshold).to.equal(2);
+
+        try {
+            await program.methods
+                .updateVault(recipient, new BN(600), "Owner Only", null, null)
+                .accounts({
+                    vault: vaultPda,
+                    owner: payer.publicKey,
+                })
+                .rpc();
+            expect.fail("Should have thrown InsufficientSignatures error");
+        } catch (err: any) {
+            expect(err.error.errorCode.code).to.equal("InsufficientSignatures");
+        }
+
+        await program.methods
+            .updateVault(recipient, new BN(600), "Both Signed", null, null)
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+            })
+            .remainingAccounts([
+                { pubkey: coOwner.publicKey, isSigner: true, isWritable: false },
+            ])
+            .signers([coOwner])
+            .rpc();
+    });
+
+    it("setClaimRestrictions - Guardian requires a restricted destination", async () => {
+        const vaultSeed = new BN(780001);
+        const recipient = Keypair.generate().publicKey;
+        const guardian = Keypair.generate().publicKey;
+        const trust = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Minor Heir", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const claimNotBefore = new BN(2000000000);
+
+        try {
+            await program.methods
+                .setClaimRestrictions(claimNotBefore, guardian, null)
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown InvalidDestination error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("InvalidDestination");
+        }
+
+        await program.methods
+            .setClaimRestrictions(claimNotBefore, guardian, trust)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.claimNotBefore.toString()).to.equal(claimNotBefore.toString());
+        expect(vaultAccount.minorGuardian?.toString()).to.equal(guardian.toString());
+        expect(vaultAccount.guardianDestination?.toString()).to.equal(trust.toString());
+    });
+
+    it("makeIrrevocable - Blocks redirecting or closing the vault", async () => {
+        const vaultSeed = new BN(880010);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Settlement", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .makeIrrevocable()
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.isIrrevocable).to.be.true;
+
+        try {
+            await program.methods
+                .updateVault(Keypair.generate().publicKey, null, null, null, null)
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown VaultIrrevocable error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("VaultIrrevocable");
+        }
+
+        try {
+            await program.methods
+                .closeVault()
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown VaultIrrevocable error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("VaultIrrevocable");
+        }
+
+        // A longer interval is still allowed
+        await program.methods
+            .updateVault(null, new BN(600), null, null, null)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+    });
 });
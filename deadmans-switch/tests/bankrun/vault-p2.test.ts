@@ -1,8 +1,9 @@
 import { describe, it, before } from "mocha";
 import { expect } from "chai";
 import { setupBankrun } from "./setup";
-import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { PublicKey, SystemProgram, Keypair, Ed25519Program, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
+import { createHash } from "crypto";
 
 describe("Vault P2 Edge Case Tests", () => {
     let context;
@@ -320,4 +321,535 @@ describe("Vault P2 Edge Case Tests", () => {
             expect(err.error?.errorCode?.code).to.equal("InvalidAmount");
         }
     });
+
+    it("closeVault - 2-of-2 authority closes via proposal", async () => {
+        const vaultSeed = new BN(960001);
+        const recipient = Keypair.generate().publicKey;
+        const authority = Keypair.generate();
+        const proposalNonce = new BN(1);
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [proposalPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("proposal"),
+                vaultPda.toBuffer(),
+                proposalNonce.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Multisig Close", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setOwnerAuthorities([authority.publicKey], 2)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        // Owner alone can no longer close
+        try {
+            await program.methods
+                .closeVault()
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown InsufficientSignatures error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("InsufficientSignatures");
+        }
+
+        // close_vault takes no arguments: args hash is SHA-256 of empty Borsh bytes
+        const argsHash = Array.from(createHash("sha256").update(Buffer.alloc(0)).digest());
+
+        await program.methods
+            .createProposal(proposalNonce, { closeVault: {} }, argsHash)
+            .accounts({
+                vault: vaultPda,
+                proposal: proposalPda,
+                proposer: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .approveProposal()
+            .accounts({
+                vault: vaultPda,
+                proposal: proposalPda,
+                approver: authority.publicKey,
+            })
+            .signers([authority])
+            .rpc();
+
+        await program.methods
+            .closeVault()
+            .accounts({ vault: vaultPda, owner: payer.publicKey, proposal: proposalPda })
+            .rpc();
+
+        const proposal = await program.account.proposal.fetch(proposalPda);
+        expect(proposal.executed).to.be.true;
+    });
+
+    it("snooze - Ping restores the normal interval", async () => {
+        const vaultSeed = new BN(970001);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Vacation", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Snooze for 30 days
+        const clock = await banksClient.getClock();
+        const until = new BN(Number(clock.unixTimestamp) + 30 * 24 * 60 * 60);
+
+        await program.methods
+            .snooze(until)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        let account = await program.account.vault.fetch(vaultPda);
+        expect(account.snoozedUntil.toString()).to.equal(until.toString());
+
+        await program.methods
+            .ping()
+            .accounts({ vault: vaultPda, signer: payer.publicKey })
+            .rpc();
+
+        account = await program.account.vault.fetch(vaultPda);
+        expect(account.snoozedUntil.toNumber()).to.equal(0);
+    });
+
+    it("succeed - Heir can only take over a released vault", async () => {
+        const vaultSeed = new BN(970002);
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        // Payer names itself recipient so it can sign as the heir
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", payer.publicKey, new BN(300), new BN(0), "Family Treasury", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        try {
+            await program.methods
+                .succeed(Keypair.generate().publicKey, "newCid", "newKey")
+                .accounts({ vault: vaultPda, heir: payer.publicKey, estate: null })
+                .rpc();
+            expect.fail("Should have thrown NotReleased error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NotReleased");
+        }
+    });
+
+    it("releaseNow - Owner releases immediately and recipient claims", async () => {
+        const vaultSeed = new BN(970003);
+        const recipient = Keypair.generate();
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient.publicKey, new BN(365 * 24 * 60 * 60), new BN(100000), "Hand Over", new BN(1000000)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        let account = await program.account.vault.fetch(vaultPda);
+        expect(account.isReleased).to.be.true;
+        expect(account.bountyLamports.toNumber()).to.equal(0);
+
+        // Same claim path as a timer-driven release
+        await program.methods
+            .claimSol()
+            .accounts({
+                vault: vaultPda,
+                allocationTable: null,
+                recipient: recipient.publicKey,
+                autoStake: null,
+                stakeTarget: null,
+                stakeHistory: null,
+                stakeProgram: null,
+                clock: null,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([recipient])
+            .rpc();
+
+        account = await program.account.vault.fetch(vaultPda);
+        expect(account.lockedLamports.toNumber()).to.equal(0);
+    });
+
+    it("releaseNow - Unrefunded bounty passes to the recipient", async () => {
+        const vaultSeed = new BN(970008);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(365 * 24 * 60 * 60), new BN(100000), "Gift Early", new BN(1000000)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .releaseNow(false)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        const account = await program.account.vault.fetch(vaultPda);
+        expect(account.isReleased).to.be.true;
+        expect(account.bountyLamports.toNumber()).to.equal(0);
+        expect(account.lockedLamports.toNumber()).to.equal(1100000);
+    });
+
+    it("pingWithSignature - Relayed check-in cannot be replayed", async () => {
+        const vaultSeed = new BN(970004);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Cold Storage", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const clock = await banksClient.getClock();
+        const nonce = new BN(0);
+        const expiry = new BN(Number(clock.unixTimestamp) + 3600);
+        const message = Buffer.concat([
+            program.programId.toBuffer(),
+            vaultPda.toBuffer(),
+            nonce.toArrayLike(Buffer, "le", 8),
+            expiry.toArrayLike(Buffer, "le", 8),
+        ]);
+        const sigverify = Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: payer.secretKey,
+            message,
+        });
+
+        await program.methods
+            .pingWithSignature(nonce, expiry)
+            .accounts({
+                vault: vaultPda,
+                relayer: payer.publicKey,
+                instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            })
+            .preInstructions([sigverify])
+            .rpc();
+
+        const account = await program.account.vault.fetch(vaultPda);
+        expect(account.pingNonce.toNumber()).to.equal(1);
+
+        try {
+            await program.methods
+                .pingWithSignature(nonce, expiry)
+                .accounts({
+                    vault: vaultPda,
+                    relayer: payer.publicKey,
+                    instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+                })
+                .preInstructions([sigverify])
+                .rpc();
+            expect.fail("Should have thrown InvalidPingNonce error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("InvalidPingNonce");
+        }
+    });
+
+    it("pingWithPreimage - Pings must walk the committed hash chain", async () => {
+        const vaultSeed = new BN(970005);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Proof of Life", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Chain of one: head = sha256(preimage)
+        const preimage = createHash("sha256").update("offline seed").digest();
+        const head = Array.from(createHash("sha256").update(preimage).digest());
+
+        await program.methods
+            .commitHashChain(head, 1)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        try {
+            await program.methods
+                .ping()
+                .accounts({ vault: vaultPda, signer: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown PreimageRequired error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("PreimageRequired");
+        }
+
+        // A snooze is a check-in too, so it can't bypass the chain
+        const clock = await banksClient.getClock();
+        try {
+            await program.methods
+                .snooze(new BN(Number(clock.unixTimestamp) + 24 * 60 * 60))
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown PreimageRequired error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("PreimageRequired");
+        }
+
+        await program.methods
+            .pingWithPreimage(Array.from(preimage))
+            .accounts({ vault: vaultPda, signer: payer.publicKey })
+            .rpc();
+
+        const account = await program.account.vault.fetch(vaultPda);
+        expect(account.hashChainRemaining).to.equal(0);
+
+        try {
+            await program.methods
+                .pingWithPreimage(Array.from(preimage))
+                .accounts({ vault: vaultPda, signer: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown HashChainExhausted error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("HashChainExhausted");
+        }
+    });
+
+    it("pingViaAdapter - Only counts CPIs from registered adapters", async () => {
+        const vaultSeed = new BN(970006);
+        const recipient = Keypair.generate().publicKey;
+        const governanceProgram = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "DAO Voter", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        try {
+            await program.methods
+                .setLivenessAdapters([{ programId: program.programId, minInterval: new BN(3600) }])
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown InvalidAdapter error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("InvalidAdapter");
+        }
+
+        await program.methods
+            .setLivenessAdapters([{ programId: governanceProgram, minInterval: new BN(3600) }])
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        // The adapter must forward the owner's or delegate's signature
+        const stranger = Keypair.generate();
+        try {
+            await program.methods
+                .pingViaAdapter()
+                .accounts({
+                    vault: vaultPda,
+                    signer: stranger.publicKey,
+                    instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+                })
+                .signers([stranger])
+                .rpc();
+            expect.fail("Should have thrown Unauthorized error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("Unauthorized");
+        }
+
+        // A direct call is not a CPI from an adapter
+        try {
+            await program.methods
+                .pingViaAdapter()
+                .accounts({
+                    vault: vaultPda,
+                    signer: payer.publicKey,
+                    instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+                })
+                .rpc();
+            expect.fail("Should have thrown NotLivenessAdapter error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NotLivenessAdapter");
+        }
+    });
+
+    it("announceExpiring - Pays the notification bounty once per cycle", async () => {
+        const vaultSeed = new BN(970007);
+        const recipient = Keypair.generate().publicKey;
+        const keeper = Keypair.generate();
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Reminder", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Window longer than the interval, so the vault is already inside it
+        await program.methods
+            .setExpiryWarning(new BN(600), new BN(1000000), new BN(5000000))
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .announceExpiring()
+            .accounts({ vault: vaultPda, announcer: keeper.publicKey, estate: null })
+            .signers([keeper])
+            .rpc();
+
+        const account = await program.account.vault.fetch(vaultPda);
+        expect(account.warnedAt.toNumber()).to.be.greaterThan(0);
+        expect(account.notificationPool.toNumber()).to.equal(4000000);
+
+        try {
+            await program.methods
+                .announceExpiring()
+                .accounts({ vault: vaultPda, announcer: keeper.publicKey, estate: null })
+                .signers([keeper])
+                .rpc();
+            expect.fail("Should have thrown AlreadyAnnounced error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("AlreadyAnnounced");
+        }
+    });
 });
@@ -0,0 +1,176 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+describe("Vault Social Recovery Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    async function createVault(vaultSeed: BN, name: string) {
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [recoveryPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("recovery"), vaultPda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), name, new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        return { vaultPda, recoveryPda };
+    }
+
+    it("recoverOwner - Guardians rotate the owner key", async () => {
+        const { vaultPda, recoveryPda } = await createVault(new BN(1200001), "Recovery");
+        const guardian = Keypair.generate();
+        const newOwner = Keypair.generate().publicKey;
+
+        await program.methods
+            .setRecoveryGuardians([guardian.publicKey], 1, new BN(0))
+            .accounts({
+                vault: vaultPda,
+                recovery: recoveryPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Proof-of-life set up with the lost key
+        await program.methods
+            .commitHashChain(Array.from(Buffer.alloc(32, 7)), 10)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+        await program.methods
+            .setLivenessAdapters([{ programId: Keypair.generate().publicKey, minInterval: new BN(3600) }])
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        await program.methods
+            .proposeRecovery(newOwner)
+            .accounts({ vault: vaultPda, recovery: recoveryPda, guardian: guardian.publicKey })
+            .signers([guardian])
+            .rpc();
+
+        await program.methods
+            .recoverOwner()
+            .accounts({ vault: vaultPda, recovery: recoveryPda, guardian: guardian.publicKey })
+            .signers([guardian])
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.owner.toString()).to.equal(newOwner.toString());
+        // PDA seeds keep using the original creator
+        expect(vaultAccount.creator.toString()).to.equal(payer.publicKey.toString());
+        expect(vaultAccount.delegate).to.be.null;
+        expect(vaultAccount.hashChainHead).to.be.null;
+        expect(vaultAccount.livenessAdapters.length).to.equal(0);
+    });
+
+    it("cancelRecovery - Owner cancels before timelock", async () => {
+        const { vaultPda, recoveryPda } = await createVault(new BN(1200002), "Cancel Recovery");
+        const guardian = Keypair.generate();
+
+        await program.methods
+            .setRecoveryGuardians([guardian.publicKey], 1, new BN(86400))
+            .accounts({
+                vault: vaultPda,
+                recovery: recoveryPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .proposeRecovery(Keypair.generate().publicKey)
+            .accounts({ vault: vaultPda, recovery: recoveryPda, guardian: guardian.publicKey })
+            .signers([guardian])
+            .rpc();
+
+        try {
+            await program.methods
+                .recoverOwner()
+                .accounts({ vault: vaultPda, recovery: recoveryPda, guardian: guardian.publicKey })
+                .signers([guardian])
+                .rpc();
+            expect.fail("Should have thrown RecoveryTimelocked error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("RecoveryTimelocked");
+        }
+
+        await program.methods
+            .cancelRecovery()
+            .accounts({ vault: vaultPda, recovery: recoveryPda, owner: payer.publicKey })
+            .rpc();
+
+        const recoveryAccount = await program.account.recovery.fetch(recoveryPda);
+        expect(recoveryAccount.pending).to.be.null;
+    });
+
+    it("closeVault - Closes the recovery account with the vault", async () => {
+        const { vaultPda, recoveryPda } = await createVault(new BN(1200003), "Closing Recovery");
+        const guardian = Keypair.generate();
+
+        await program.methods
+            .setRecoveryGuardians([guardian.publicKey], 1, new BN(0))
+            .accounts({
+                vault: vaultPda,
+                recovery: recoveryPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.hasRecovery).to.be.true;
+
+        // Leaving the guardians behind would hand them to a re-created vault
+        try {
+            await program.methods
+                .closeVault()
+                .accounts({ vault: vaultPda, owner: payer.publicKey, recovery: null })
+                .rpc();
+            expect.fail("Should have thrown RecoveryRequired error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("RecoveryRequired");
+        }
+
+        await program.methods
+            .closeVault()
+            .accounts({ vault: vaultPda, owner: payer.publicKey, recovery: recoveryPda })
+            .rpc();
+
+        expect(await banksClient.getAccount(vaultPda)).to.be.null;
+        expect(await banksClient.getAccount(recoveryPda)).to.be.null;
+    });
+});
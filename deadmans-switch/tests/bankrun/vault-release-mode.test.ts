@@ -0,0 +1,253 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import { Clock } from "solana-bankrun";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+describe("Vault Release Mode (Time Capsule) Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    it("setReleaseMode - Fixed date rejects pings and earlier dates", async () => {
+        const vaultSeed = new BN(1300001);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "18th Birthday", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const clock = await banksClient.getClock();
+        const releaseAt = new BN(Number(clock.unixTimestamp) + 365 * 24 * 60 * 60);
+
+        await program.methods
+            .setReleaseMode({ fixedDate: {} }, releaseAt)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.releaseMode).to.deep.equal({ fixedDate: {} });
+        expect(vaultAccount.releaseAt.toString()).to.equal(releaseAt.toString());
+
+        try {
+            await program.methods
+                .ping()
+                .accounts({ vault: vaultPda, signer: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown ReleaseModeMismatch error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("ReleaseModeMismatch");
+        }
+
+        try {
+            await program.methods
+                .updateVault(null, null, null, null, releaseAt.subn(1))
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown InvalidReleaseDate error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("InvalidReleaseDate");
+        }
+
+        // Moving the date later is allowed
+        await program.methods
+            .updateVault(null, null, null, null, releaseAt.addn(60))
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+    });
+
+    it("setReleaseAction - Destroy on expiry, not for irrevocable vaults", async () => {
+        const recipient = Keypair.generate().publicKey;
+        const vaults = [];
+
+        for (const seed of [1300002, 1300003]) {
+            const vaultSeed = new BN(seed);
+            const [vaultPda] = PublicKey.findProgramAddressSync(
+                [
+                    Buffer.from("vault"),
+                    payer.publicKey.toBuffer(),
+                    vaultSeed.toArrayLike(Buffer, "le", 8)
+                ],
+                program.programId
+            );
+
+            await program.methods
+                .initializeVault(
+                    vaultSeed,
+                    "cid", "key", recipient, new BN(300), new BN(0), "Burn Notice", new BN(0)
+                )
+                .accounts({
+                    vault: vaultPda,
+                    owner: payer.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            vaults.push(vaultPda);
+        }
+
+        await program.methods
+            .setReleaseAction({ destroyBurnTokens: {} })
+            .accounts({ vault: vaults[0], owner: payer.publicKey })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaults[0]);
+        expect(vaultAccount.releaseAction).to.deep.equal({ destroyBurnTokens: {} });
+
+        await program.methods
+            .makeIrrevocable()
+            .accounts({ vault: vaults[1], owner: payer.publicKey })
+            .rpc();
+
+        try {
+            await program.methods
+                .setReleaseAction({ destroyReturnTokens: {} })
+                .accounts({ vault: vaults[1], owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown VaultIrrevocable error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("VaultIrrevocable");
+        }
+    });
+
+    it("setReleaseNotice - Stores the recipient notice", async () => {
+        const vaultSeed = new BN(1300004);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(2000000), "Notice", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        try {
+            await program.methods
+                .setReleaseNotice(new BN(1000000), "x".repeat(65))
+                .accounts({ vault: vaultPda, owner: payer.publicKey })
+                .rpc();
+            expect.fail("Should have thrown NoticeMemoTooLong error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NoticeMemoTooLong");
+        }
+
+        await program.methods
+            .setReleaseNotice(new BN(1000000), "Check the family vault")
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.releaseNoticeLamports.toNumber()).to.equal(1000000);
+        expect(vaultAccount.releaseNoticeMemo).to.equal("Check the family vault");
+    });
+
+    it("triggerRelease - Notice too small for a new wallet stays with the locked SOL", async () => {
+        const vaultSeed = new BN(1300005);
+        const recipient = Keypair.generate().publicKey;
+        const hunter = Keypair.generate();
+        const memoProgram = new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(1), new BN(2000000), "Small Notice", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .setReleaseNotice(new BN(1000), "")
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        const clock = await banksClient.getClock();
+        context.setClock(
+            new Clock(
+                clock.slot,
+                clock.epochStartTimestamp,
+                clock.epoch,
+                clock.leaderScheduleEpoch,
+                clock.unixTimestamp + BigInt(10)
+            )
+        );
+
+        await program.methods
+            .triggerRelease()
+            .accounts({
+                vault: vaultPda,
+                hunter: hunter.publicKey,
+                estate: null,
+                escalationPlan: null,
+                owner: null,
+                tokenMint: null,
+                vaultTokenAccount: null,
+                ownerTokenAccount: null,
+                tokenProgram: null,
+                recipient,
+                memoProgram,
+            })
+            .signers([hunter])
+            .rpc();
+
+        // 1000 lamports can't open a new wallet, so the recipient claims them instead
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.isReleased).to.be.true;
+        expect(vaultAccount.lockedLamports.toNumber()).to.equal(1000);
+        expect(await banksClient.getAccount(recipient)).to.be.null;
+    });
+});
@@ -0,0 +1,504 @@
+import { describe, it, before } from "mocha";
+import { expect } from "chai";
+import { setupBankrun } from "./setup";
+import {
+    PublicKey,
+    SystemProgram,
+    Keypair,
+    StakeProgram,
+    Authorized,
+    Lockup,
+    LAMPORTS_PER_SOL,
+    SYSVAR_CLOCK_PUBKEY,
+    SYSVAR_RENT_PUBKEY,
+    SYSVAR_STAKE_HISTORY_PUBKEY,
+} from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+describe("Vault Stake Account Tests", () => {
+    let context;
+    let provider;
+    let program;
+    let banksClient;
+    let payer;
+
+    before(async () => {
+        const setup = await setupBankrun();
+        context = setup.context;
+        provider = setup.provider;
+        program = setup.program;
+        banksClient = setup.banksClient;
+        payer = setup.payer;
+    });
+
+    it("lockStakeAccount - Holds authorities until the owner reclaims them", async () => {
+        const vaultSeed = new BN(1700001);
+        const recipient = Keypair.generate().publicKey;
+        const stakeAccount = Keypair.generate();
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [stakeRecordPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("stake"), vaultPda.toBuffer(), stakeAccount.publicKey.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Staked Estate", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const createStake = StakeProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            stakePubkey: stakeAccount.publicKey,
+            authorized: new Authorized(payer.publicKey, payer.publicKey),
+            lockup: new Lockup(0, 0, PublicKey.default),
+            lamports: LAMPORTS_PER_SOL,
+        });
+
+        await program.methods
+            .lockStakeAccount()
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                stakeAccount: stakeAccount.publicKey,
+                stakeRecord: stakeRecordPda,
+                custodian: null,
+                stakeProgram: StakeProgram.programId,
+                clock: SYSVAR_CLOCK_PUBKEY,
+                systemProgram: SystemProgram.programId,
+            })
+            .preInstructions(createStake.instructions)
+            .signers([stakeAccount])
+            .rpc();
+
+        let vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.lockedStakeAccounts).to.equal(1);
+
+        const record = await program.account.stakeRecord.fetch(stakeRecordPda);
+        expect(record.stakeAccount.toString()).to.equal(stakeAccount.publicKey.toString());
+
+        // The vault cannot be closed while it holds the stake account
+        try {
+            await program.methods
+                .closeVault()
+                .accounts({
+                    vault: vaultPda,
+                    owner: payer.publicKey,
+                    proposal: null,
+                })
+                .rpc();
+            expect.fail("Should have thrown StakeAccountsStillLocked error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("StakeAccountsStillLocked");
+        }
+
+        await program.methods
+            .reclaimStakeAccount()
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                stakeAccount: stakeAccount.publicKey,
+                stakeRecord: stakeRecordPda,
+                custodian: null,
+                proposal: null,
+                stakeProgram: StakeProgram.programId,
+                clock: SYSVAR_CLOCK_PUBKEY,
+            })
+            .rpc();
+
+        vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.lockedStakeAccounts).to.equal(0);
+        expect(await banksClient.getAccount(stakeRecordPda)).to.be.null;
+    });
+
+    it("stakeLockedSol - Requires locked SOL to delegate", async () => {
+        const vaultSeed = new BN(1700002);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [autoStakePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("auto_stake"), vaultPda.toBuffer()],
+            program.programId
+        );
+        const [stakeAccountPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("auto_stake"), vaultPda.toBuffer(), Buffer.from("native")],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Idle Estate", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        try {
+            await program.methods
+                .stakeLockedSol()
+                .accounts({
+                    vault: vaultPda,
+                    owner: payer.publicKey,
+                    autoStake: autoStakePda,
+                    stakeAccount: stakeAccountPda,
+                    voteAccount: Keypair.generate().publicKey,
+                    stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+                    stakeConfig: new PublicKey("StakeConfig11111111111111111111111111111111"),
+                    proposal: null,
+                    stakeProgram: StakeProgram.programId,
+                    rent: SYSVAR_RENT_PUBKEY,
+                    clock: SYSVAR_CLOCK_PUBKEY,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            expect.fail("Should have thrown NoLockedSol error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NoLockedSol");
+        }
+    });
+
+    it("claimSol - Only a claimant can settle auto-staked SOL", async () => {
+        const vaultSeed = new BN(1700003);
+        const recipient = Keypair.generate().publicKey;
+        const stranger = Keypair.generate();
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [autoStakePda, autoStakeBump] = PublicKey.findProgramAddressSync(
+            [Buffer.from("auto_stake"), vaultPda.toBuffer()],
+            program.programId
+        );
+        const [stakeAccountPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("auto_stake"), vaultPda.toBuffer(), Buffer.from("native")],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Staked Claim", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: vaultPda, owner: payer.publicKey })
+            .rpc();
+
+        // Stand in for SOL delegated through stakeLockedSol, which needs a live vote account
+        const rawVault = await banksClient.getAccount(vaultPda);
+        const vaultState = program.coder.accounts.decode("vault", Buffer.from(rawVault.data));
+        const vaultData = Buffer.alloc(rawVault.data.length);
+        // Its claim window has already lapsed, with a fallback waiting
+        const fallback = Keypair.generate().publicKey;
+        (await program.coder.accounts.encode("vault", {
+            ...vaultState,
+            hasAutoStake: true,
+            fallbackRecipients: [fallback],
+            claimDeadline: new BN(1),
+            releasedAt: vaultState.releasedAt.subn(100),
+        })).copy(vaultData);
+        context.setAccount(vaultPda, { ...rawVault, data: vaultData });
+
+        const autoStakeData = await program.coder.accounts.encode("autoStake", {
+            vault: vaultPda,
+            mode: { nativeStake: {} },
+            target: stakeAccountPda,
+            principal: new BN(LAMPORTS_PER_SOL),
+            bump: autoStakeBump,
+        });
+        context.setAccount(autoStakePda, {
+            lamports: LAMPORTS_PER_SOL,
+            data: autoStakeData,
+            owner: program.programId,
+            executable: false,
+        });
+
+        try {
+            await program.methods
+                .claimSol()
+                .accounts({
+                    vault: vaultPda,
+                    allocationTable: null,
+                    recipient: stranger.publicKey,
+                    autoStake: autoStakePda,
+                    stakeTarget: stakeAccountPda,
+                    stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+                    stakeProgram: StakeProgram.programId,
+                    clock: SYSVAR_CLOCK_PUBKEY,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([stranger])
+                .rpc();
+            expect.fail("Should have thrown NotRecipient error");
+        } catch (err: any) {
+            expect(err.error?.errorCode?.code).to.equal("NotRecipient");
+        }
+
+        // The position and its rent are untouched
+        expect(await banksClient.getAccount(autoStakePda)).to.not.be.null;
+
+        // Staked SOL alone is still something to claim, so the rights move on
+        await program.methods
+            .passClaimRights()
+            .accounts({ vault: vaultPda, caller: payer.publicKey, allocationTable: null })
+            .rpc();
+
+        let vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.recipient.toString()).to.equal(fallback.toString());
+
+        // An undelegated stake account held by the vault: Initialized meta with the
+        // vault as staker and withdrawer
+        const stakeData = Buffer.alloc(StakeProgram.space);
+        stakeData.writeUInt32LE(1, 0);
+        vaultPda.toBuffer().copy(stakeData, 12);
+        vaultPda.toBuffer().copy(stakeData, 44);
+        context.setAccount(stakeAccountPda, {
+            lamports: LAMPORTS_PER_SOL,
+            data: stakeData,
+            owner: StakeProgram.programId,
+            executable: false,
+        });
+
+        // Anyone, e.g. a minor's guardian, may settle it back into locked SOL
+        await program.methods
+            .settleAutoStake()
+            .accounts({
+                vault: vaultPda,
+                caller: stranger.publicKey,
+                autoStake: autoStakePda,
+                stakeTarget: stakeAccountPda,
+                stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+                stakeProgram: StakeProgram.programId,
+                clock: SYSVAR_CLOCK_PUBKEY,
+            })
+            .signers([stranger])
+            .rpc();
+
+        vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.hasAutoStake).to.be.false;
+        expect(vaultAccount.lockedLamports.toNumber()).to.equal(2 * LAMPORTS_PER_SOL);
+        expect(await banksClient.getAccount(autoStakePda)).to.be.null;
+    });
+
+    it("unstakeLockedSol - Owner returns auto-staked SOL to locked SOL before release", async () => {
+        const vaultSeed = new BN(1700006);
+        const recipient = Keypair.generate().publicKey;
+
+        const [vaultPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        );
+        const [autoStakePda, autoStakeBump] = PublicKey.findProgramAddressSync(
+            [Buffer.from("auto_stake"), vaultPda.toBuffer()],
+            program.programId
+        );
+        const [stakeAccountPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("auto_stake"), vaultPda.toBuffer(), Buffer.from("native")],
+            program.programId
+        );
+
+        await program.methods
+            .initializeVault(
+                vaultSeed,
+                "cid", "key", recipient, new BN(300), new BN(0), "Unstake", new BN(0)
+            )
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Stand in for SOL staked through stakeLockedSol, already undelegated
+        const rawVault = await banksClient.getAccount(vaultPda);
+        const vaultState = program.coder.accounts.decode("vault", Buffer.from(rawVault.data));
+        const vaultData = Buffer.alloc(rawVault.data.length);
+        (await program.coder.accounts.encode("vault", {
+            ...vaultState,
+            hasAutoStake: true,
+        })).copy(vaultData);
+        context.setAccount(vaultPda, { ...rawVault, data: vaultData });
+
+        const autoStakeData = await program.coder.accounts.encode("autoStake", {
+            vault: vaultPda,
+            mode: { nativeStake: {} },
+            target: stakeAccountPda,
+            principal: new BN(LAMPORTS_PER_SOL),
+            bump: autoStakeBump,
+        });
+        context.setAccount(autoStakePda, {
+            lamports: LAMPORTS_PER_SOL,
+            data: autoStakeData,
+            owner: program.programId,
+            executable: false,
+        });
+
+        const stakeData = Buffer.alloc(StakeProgram.space);
+        stakeData.writeUInt32LE(1, 0);
+        vaultPda.toBuffer().copy(stakeData, 12);
+        vaultPda.toBuffer().copy(stakeData, 44);
+        context.setAccount(stakeAccountPda, {
+            lamports: LAMPORTS_PER_SOL,
+            data: stakeData,
+            owner: StakeProgram.programId,
+            executable: false,
+        });
+
+        await program.methods
+            .unstakeLockedSol()
+            .accounts({
+                vault: vaultPda,
+                owner: payer.publicKey,
+                autoStake: autoStakePda,
+                stakeTarget: stakeAccountPda,
+                stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+                stakeProgram: StakeProgram.programId,
+                clock: SYSVAR_CLOCK_PUBKEY,
+                proposal: null,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const vaultAccount = await program.account.vault.fetch(vaultPda);
+        expect(vaultAccount.hasAutoStake).to.be.false;
+        expect(vaultAccount.lockedLamports.toNumber()).to.equal(LAMPORTS_PER_SOL);
+        expect(await banksClient.getAccount(autoStakePda)).to.be.null;
+    });
+
+    it("cascadeClaimStakeAccount - Anyone forwards a stake account into a recipient vault", async () => {
+        const heirSeed = new BN(1700004);
+        const sourceSeed = new BN(1700005);
+        const heirRecipient = Keypair.generate().publicKey;
+        const stakeAccount = Keypair.generate();
+
+        const vaultAddress = (vaultSeed: BN) => PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("vault"),
+                payer.publicKey.toBuffer(),
+                vaultSeed.toArrayLike(Buffer, "le", 8)
+            ],
+            program.programId
+        )[0];
+        const heirVault = vaultAddress(heirSeed);
+        const sourceVault = vaultAddress(sourceSeed);
+        const stakeRecord = (vault: PublicKey) => PublicKey.findProgramAddressSync(
+            [Buffer.from("stake"), vault.toBuffer(), stakeAccount.publicKey.toBuffer()],
+            program.programId
+        )[0];
+
+        for (const [vaultSeed, vaultPda, recipient, name] of [
+            [heirSeed, heirVault, heirRecipient, "Heir Vault"],
+            [sourceSeed, sourceVault, heirVault, "Parent Vault"],
+        ] as [BN, PublicKey, PublicKey, string][]) {
+            await program.methods
+                .initializeVault(
+                    vaultSeed,
+                    "cid", "key", recipient, new BN(300), new BN(0), name, new BN(0)
+                )
+                .accounts({
+                    vault: vaultPda,
+                    owner: payer.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+        }
+
+        const createStake = StakeProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            stakePubkey: stakeAccount.publicKey,
+            authorized: new Authorized(payer.publicKey, payer.publicKey),
+            lockup: new Lockup(0, 0, PublicKey.default),
+            lamports: LAMPORTS_PER_SOL,
+        });
+
+        await program.methods
+            .lockStakeAccount()
+            .accounts({
+                vault: sourceVault,
+                owner: payer.publicKey,
+                stakeAccount: stakeAccount.publicKey,
+                stakeRecord: stakeRecord(sourceVault),
+                custodian: null,
+                stakeProgram: StakeProgram.programId,
+                clock: SYSVAR_CLOCK_PUBKEY,
+                systemProgram: SystemProgram.programId,
+            })
+            .preInstructions(createStake.instructions)
+            .signers([stakeAccount])
+            .rpc();
+
+        await program.methods
+            .releaseNow(true)
+            .accounts({ vault: sourceVault, owner: payer.publicKey })
+            .rpc();
+
+        await program.methods
+            .cascadeClaimStakeAccount()
+            .accounts({
+                vault: sourceVault,
+                targetVault: heirVault,
+                caller: payer.publicKey,
+                stakeAccount: stakeAccount.publicKey,
+                stakeRecord: stakeRecord(sourceVault),
+                targetStakeRecord: stakeRecord(heirVault),
+                custodian: null,
+                stakeProgram: StakeProgram.programId,
+                clock: SYSVAR_CLOCK_PUBKEY,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts([
+                { pubkey: heirRecipient, isSigner: false, isWritable: false },
+            ])
+            .rpc();
+
+        expect((await program.account.vault.fetch(sourceVault)).lockedStakeAccounts).to.equal(0);
+        expect((await program.account.vault.fetch(heirVault)).lockedStakeAccounts).to.equal(1);
+        expect(await banksClient.getAccount(stakeRecord(sourceVault))).to.be.null;
+
+        // Staker and withdrawer now both belong to the recipient vault
+        const data = Buffer.from((await banksClient.getAccount(stakeAccount.publicKey)).data);
+        expect(new PublicKey(data.subarray(12, 44)).toString()).to.equal(heirVault.toString());
+        expect(new PublicKey(data.subarray(44, 76)).toString()).to.equal(heirVault.toString());
+    });
+});